@@ -347,6 +347,7 @@ value = "${{ BUILD_ID }}"
                         value,
                         separator: ':',
                         alignment: SeparatorAlignment::default(),
+                        span: None,
                     });
                 }
 
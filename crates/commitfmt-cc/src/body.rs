@@ -1,16 +1,18 @@
 use memchr::memmem;
 
 use crate::footer::Footers;
+use crate::span::SourceMap;
 
 pub(crate) const DEFAULT_COMMENT_SYMBOL: &str = "#";
 const OLD_CONFLICTS_TITLE: &str = "Conflicts:";
 
-/// Parse body and footer
-pub(crate) fn parse_body(
-    input: &str,
+/// Parse body and footer.
+/// Footer spans are relative to the input.
+pub(crate) fn parse_body<'input>(
+    input: &'input str,
     footer_separators: &str,
     comment_symbol: &str,
-) -> (Option<String>, Option<Footers>) {
+) -> (Option<&'input str>, Option<Footers>) {
     if input.is_empty() {
         return (None, None);
     }
@@ -30,21 +32,13 @@ pub(crate) fn parse_body(
         None => ("", meaningful_input),
     };
 
-    parse_footer_candidate(meaningful_input, body, footer_candidate, footer_separators)
-}
-
-fn parse_footer_candidate(
-    meaningful_input: &str,
-    body: &str,
-    footer_candidate: &str,
-    footer_separators: &str,
-) -> (Option<String>, Option<Footers>) {
     match Footers::parse(footer_candidate, footer_separators) {
-        Ok((_rest, footers)) => {
-            let body = if body.is_empty() { None } else { Some(body.to_string()) };
+        Ok((_rest, mut footers)) => {
+            footers.shift_spans(SourceMap::new(input).span_of(footer_candidate).start);
+            let body = if body.is_empty() { None } else { Some(body) };
             (body, Some(footers))
         }
-        Err(_) => (Some(meaningful_input.to_string()), None),
+        Err(_) => (Some(meaningful_input), None),
     }
 }
 
@@ -157,34 +151,46 @@ mod tests {
     #[test]
     fn test_parse_body() {
         let input = "my body";
-        let expected = (Some("my body".to_string()), None);
+        let expected = (Some("my body"), None);
         assert_eq!(parse_body(input, ":", "#"), expected);
 
         let input = "\nmy body";
-        let expected = (Some("my body".to_string()), None);
+        let expected = (Some("my body"), None);
         assert_eq!(parse_body(input, ":", "#"), expected);
 
         let input = "\n\nmy body";
-        let expected = (Some("my body".to_string()), None);
+        let expected = (Some("my body"), None);
         assert_eq!(parse_body(input, ":", "#"), expected);
 
         let input = "\n\n\nmy body";
-        let expected = (Some("my body".to_string()), None);
+        let expected = (Some("my body"), None);
         assert_eq!(parse_body(input, ":", "#"), expected);
 
         let input = "my body\n\nmyfooter: my value";
         let expected = (
-            Some("my body".to_string()),
+            Some("my body"),
             Some(footer_vec![{
                 key: "myfooter".to_string(),
                 value: "my value".to_string(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             }]),
         );
         assert_eq!(parse_body(input, ":", "#"), expected);
     }
 
+    #[test]
+    fn test_parse_body_footer_spans() {
+        let input = "my body\n\nmyfooter: my value\nother: multi\n line";
+        let (_, footers) = parse_body(input, ":", "#");
+        let footers = footers.unwrap();
+
+        assert_eq!(footers.get(0).unwrap().span.unwrap().to_string(), "3:1-3:19");
+        assert_eq!(footers.get(1).unwrap().span.unwrap().to_string(), "4:1-5:6");
+        assert_eq!(footers.get(1).unwrap().key_span().unwrap().to_string(), "4:1-4:6");
+    }
+
     #[test]
     fn test_parse_body_with_comments() {
         let input = "my cool feature
@@ -194,12 +200,13 @@ Authored-By: Co Mitter <comitter@example.com>
 # This is a comment
 # This is another comment";
         let expected = (
-            Some("my cool feature".to_string()),
+            Some("my cool feature"),
             Some(footer_vec![ {
                 key: "Authored-By".to_string(),
                 value: "Co Mitter <comitter@example.com>".to_string(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             }]),
         );
         assert_eq!(parse_body(input, ":", "#"), expected);
@@ -208,7 +215,7 @@ Authored-By: Co Mitter <comitter@example.com>
     #[test]
     fn test_parse_body_drops_trailing_comments_without_footers() {
         let input = "para1\n\npara2\n\n# Please enter the commit message...";
-        let expected = (Some("para1\n\npara2".to_string()), None);
+        let expected = (Some("para1\n\npara2"), None);
         assert_eq!(parse_body(input, ":", "#"), expected);
     }
 
@@ -222,6 +229,7 @@ Authored-By: Co Mitter <comitter@example.com>
                 value: "my value".to_string(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             }]),
         );
         assert_eq!(parse_body(input, ":", "#"), expected);
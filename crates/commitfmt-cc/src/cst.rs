@@ -0,0 +1,502 @@
+use std::fmt::Display;
+use std::ops::Range;
+
+use crate::body::DEFAULT_COMMENT_SYMBOL;
+use crate::{Footer, Message, Span};
+
+/// Kind of a node in the concrete syntax tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SyntaxKind {
+    /// First line of the message
+    Header,
+    /// Commit type, e.g. `feat`
+    Type,
+    /// Scope with parentheses, e.g. `(api, ui)`
+    Scope,
+    /// Exclamation mark after the type or scope
+    BreakingMark,
+    /// Colon between the header prefix and the description
+    Colon,
+    Description,
+    Body,
+    /// Footer with its key, separator and value
+    Footer,
+    FooterKey,
+    FooterSeparator,
+    /// Footer value including continuation lines
+    FooterValue,
+    /// Line starting with the comment symbol
+    Comment,
+    /// Spaces and tabs
+    Whitespace,
+    Newline,
+    /// Text that is dropped by the parser, e.g. the old `Conflicts:` block
+    Ignored,
+}
+
+/// Node of the concrete syntax tree.
+/// Nodes without children are tokens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxNode {
+    pub kind: SyntaxKind,
+    /// Byte range of the node in the source text
+    pub range: Range<usize>,
+    pub children: Vec<SyntaxNode>,
+}
+
+impl SyntaxNode {
+    fn token(kind: SyntaxKind, range: Range<usize>) -> Self {
+        Self { kind, range, children: Vec::new() }
+    }
+
+    /// Returns the first child of the kind
+    pub fn child(&self, kind: SyntaxKind) -> Option<&SyntaxNode> {
+        self.children.iter().find(|node| node.kind == kind)
+    }
+
+    /// Returns an iterator over the node and all its descendants in source order
+    pub fn descendants(&self) -> impl Iterator<Item = &SyntaxNode> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+}
+
+/// Lossless syntax tree of a commit message.
+///
+/// Unlike [`Message`], the tree keeps whitespace, comments and the original separators,
+/// so the source text can be changed with minimal edits.
+#[derive(Debug, Clone)]
+pub struct SyntaxTree {
+    source: String,
+    message: Message,
+    nodes: Vec<SyntaxNode>,
+}
+
+impl SyntaxTree {
+    pub fn parse(
+        input: &str,
+        footer_separators: Option<&str>,
+        comment_symbol: Option<&str>,
+    ) -> Self {
+        let message = Message::parse(input, footer_separators, comment_symbol);
+        let comment_symbol = comment_symbol.unwrap_or(DEFAULT_COMMENT_SYMBOL);
+
+        let mut components = Vec::new();
+        if let Some(span) = message.header.span {
+            components.push(header_node(input, &message, span));
+        }
+        if let Some(span) = message.body_span {
+            components.push(SyntaxNode::token(SyntaxKind::Body, span.range()));
+        }
+        for footer in message.footers.iter() {
+            if let Some(span) = footer.span {
+                components.push(footer_node(input, footer, span));
+            }
+        }
+
+        let nodes = fill_gaps(input, 0..input.len(), components, |gap, offset, nodes| {
+            push_trivia(gap, offset, comment_symbol, nodes);
+        });
+
+        Self { source: input.to_string(), message, nodes }
+    }
+
+    /// Returns the source text of the tree
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the message parsed from the source text
+    pub fn message(&self) -> &Message {
+        &self.message
+    }
+
+    /// Returns the top-level nodes
+    pub fn nodes(&self) -> &[SyntaxNode] {
+        &self.nodes
+    }
+
+    /// Returns the source text of the node
+    pub fn text(&self, node: &SyntaxNode) -> &str {
+        &self.source[node.range.clone()]
+    }
+
+    /// Returns an iterator over all nodes of the kind in source order
+    pub fn find(&self, kind: SyntaxKind) -> impl Iterator<Item = &SyntaxNode> {
+        self.nodes
+            .iter()
+            .flat_map(SyntaxNode::descendants)
+            .filter(move |node| node.kind == kind)
+    }
+
+    /// Returns the parsed footers with their nodes
+    pub fn footers(&self) -> impl Iterator<Item = (&Footer, &SyntaxNode)> {
+        self.message.footers.iter().filter_map(|footer| {
+            let range = footer.span?.range();
+            let node = self
+                .nodes
+                .iter()
+                .find(|node| node.kind == SyntaxKind::Footer && node.range == range)?;
+            Some((footer, node))
+        })
+    }
+
+    /// Returns the edit that deletes the node with its line break
+    pub fn delete_line(&self, node: &SyntaxNode) -> Edit {
+        let Range { start, end } = node.range;
+        if self.source[end..].starts_with('\n') {
+            Edit::delete(start..end + 1)
+        } else if self.source[..start].ends_with('\n') {
+            Edit::delete(start - 1..end)
+        } else {
+            Edit::delete(start..end)
+        }
+    }
+
+    /// Returns the edit that appends the footer after the last footer.
+    /// If there are no footers, the footer is separated from the header or body
+    /// with an empty line. `None` if the message has neither of them.
+    pub fn append_footer(&self, footer: &Footer) -> Option<Edit> {
+        if let Some(node) =
+            self.nodes.iter().rev().find(|node| node.kind == SyntaxKind::Footer)
+        {
+            return Some(Edit::insert(node.range.end, format!("\n{footer}")));
+        }
+        let node = self
+            .nodes
+            .iter()
+            .rev()
+            .filter(|node| !node.range.is_empty())
+            .find(|node| matches!(node.kind, SyntaxKind::Header | SyntaxKind::Body))?;
+        Some(Edit::insert(node.range.end, format!("\n\n{footer}")))
+    }
+
+    /// Applies the edits to the source text.
+    /// Edits that overlap an already applied edit are skipped.
+    pub fn apply(&self, edits: &[Edit]) -> String {
+        let mut edits: Vec<&Edit> = edits.iter().collect();
+        edits.sort_by_key(|edit| (edit.range.start, edit.range.end));
+
+        let mut result = String::with_capacity(self.source.len());
+        let mut position = 0;
+        for edit in edits {
+            if edit.range.start < position {
+                continue;
+            }
+            result.push_str(&self.source[position..edit.range.start]);
+            result.push_str(&edit.content);
+            position = edit.range.end;
+        }
+        result.push_str(&self.source[position..]);
+
+        result
+    }
+}
+
+impl Display for SyntaxTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for token in self.nodes.iter().flat_map(SyntaxNode::descendants) {
+            if token.children.is_empty() {
+                write!(f, "{}", self.text(token))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Replacement of a byte range of the source text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub range: Range<usize>,
+    pub content: String,
+}
+
+impl Edit {
+    pub fn replace(range: Range<usize>, content: impl Into<String>) -> Self {
+        Self { range, content: content.into() }
+    }
+
+    pub fn insert(offset: usize, content: impl Into<String>) -> Self {
+        Self { range: offset..offset, content: content.into() }
+    }
+
+    pub fn delete(range: Range<usize>) -> Self {
+        Self { range, content: String::new() }
+    }
+}
+
+fn header_node(input: &str, message: &Message, span: Span) -> SyntaxNode {
+    let header = &message.header;
+    let mut components = Vec::new();
+    if let Some(span) = header.kind_span {
+        components.push(SyntaxNode::token(SyntaxKind::Type, span.range()));
+    }
+    if let Some(span) = header.scope.span {
+        components.push(SyntaxNode::token(SyntaxKind::Scope, span.range()));
+    }
+    if let Some(span) = header.description_span {
+        components.push(SyntaxNode::token(SyntaxKind::Description, span.range()));
+    }
+
+    let children = fill_gaps(input, span.range(), components, |gap, offset, nodes| {
+        push_tokens(gap, offset, nodes, |ch| match ch {
+            '!' => SyntaxKind::BreakingMark,
+            ':' => SyntaxKind::Colon,
+            _ => SyntaxKind::Ignored,
+        });
+    });
+
+    SyntaxNode { kind: SyntaxKind::Header, range: span.range(), children }
+}
+
+fn footer_node(input: &str, footer: &Footer, span: Span) -> SyntaxNode {
+    let key_end = span.start.offset + footer.key.len();
+    let separator_start = skip_whitespace(input, key_end);
+    let separator_end = separator_start + footer.separator.len_utf8();
+    let value_start = skip_whitespace(input, separator_end).min(span.end.offset);
+
+    let mut children =
+        vec![SyntaxNode::token(SyntaxKind::FooterKey, span.start.offset..key_end)];
+    push_tokens(&input[key_end..separator_start], key_end, &mut children, |_| {
+        SyntaxKind::Ignored
+    });
+    children
+        .push(SyntaxNode::token(SyntaxKind::FooterSeparator, separator_start..separator_end));
+    push_tokens(&input[separator_end..value_start], separator_end, &mut children, |_| {
+        SyntaxKind::Ignored
+    });
+    children.push(SyntaxNode::token(SyntaxKind::FooterValue, value_start..span.end.offset));
+
+    SyntaxNode { kind: SyntaxKind::Footer, range: span.range(), children }
+}
+
+/// Returns the offset of the first character after spaces and tabs
+fn skip_whitespace(input: &str, offset: usize) -> usize {
+    let rest = &input[offset..];
+    offset + rest.len() - rest.trim_start_matches([' ', '\t']).len()
+}
+
+/// Returns nodes that cover the whole range.
+/// The text between the components is tokenized with `gap`.
+fn fill_gaps(
+    input: &str,
+    range: Range<usize>,
+    mut components: Vec<SyntaxNode>,
+    gap: impl Fn(&str, usize, &mut Vec<SyntaxNode>),
+) -> Vec<SyntaxNode> {
+    components.sort_by_key(|node| node.range.start);
+
+    let mut nodes = Vec::new();
+    let mut position = range.start;
+    for node in components {
+        if node.range.start > position {
+            gap(&input[position..node.range.start], position, &mut nodes);
+        }
+        position = position.max(node.range.end);
+        nodes.push(node);
+    }
+    if range.end > position {
+        gap(&input[position..range.end], position, &mut nodes);
+    }
+
+    nodes
+}
+
+/// Splits the text between message components into lines of trivia
+fn push_trivia(text: &str, offset: usize, comment_symbol: &str, nodes: &mut Vec<SyntaxNode>) {
+    let mut line_start = offset;
+    for line in text.split_inclusive('\n') {
+        let content = line.strip_suffix('\n').unwrap_or(line);
+        let content_end = line_start + content.len();
+
+        if !content.is_empty() {
+            let kind = if content.trim().is_empty() {
+                SyntaxKind::Whitespace
+            } else if content.starts_with(comment_symbol) {
+                SyntaxKind::Comment
+            } else {
+                SyntaxKind::Ignored
+            };
+            nodes.push(SyntaxNode::token(kind, line_start..content_end));
+        }
+        if content.len() < line.len() {
+            nodes.push(SyntaxNode::token(SyntaxKind::Newline, content_end..content_end + 1));
+        }
+
+        line_start += line.len();
+    }
+}
+
+/// Splits the text into whitespace runs and single character tokens
+fn push_tokens(
+    text: &str,
+    offset: usize,
+    nodes: &mut Vec<SyntaxNode>,
+    kind: impl Fn(char) -> SyntaxKind,
+) {
+    let mut whitespace_start: Option<usize> = None;
+    for (index, ch) in text.char_indices() {
+        let start = offset + index;
+        if ch.is_whitespace() {
+            whitespace_start.get_or_insert(start);
+            continue;
+        }
+        if let Some(whitespace) = whitespace_start.take() {
+            nodes.push(SyntaxNode::token(SyntaxKind::Whitespace, whitespace..start));
+        }
+        nodes.push(SyntaxNode::token(kind(ch), start..start + ch.len_utf8()));
+    }
+    if let Some(whitespace) = whitespace_start {
+        nodes.push(SyntaxNode::token(SyntaxKind::Whitespace, whitespace..offset + text.len()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(tree: &SyntaxTree) -> Vec<(SyntaxKind, &str)> {
+        tree.nodes
+            .iter()
+            .flat_map(SyntaxNode::descendants)
+            .filter(|node| node.children.is_empty())
+            .map(|node| (node.kind, tree.text(node)))
+            .collect()
+    }
+
+    #[test]
+    fn test_lossless() {
+        let inputs = [
+            "",
+            "feat: my feature",
+            "feat(api, ui) !:  my feature.  \n\n\n  body  with  spaces\n",
+            "# comment\nfeat: x\n\nbody\n# inner\nbody\n\nRefs #123\nKey :  multi\n line\n\n# tail\n",
+            "fix: conflict\n\nbody\nConflicts:\n\tfile1\n",
+            "not a conventional header\r\n\r\nbody",
+        ];
+        for input in inputs {
+            let tree = SyntaxTree::parse(input, Some(":#"), None);
+            assert_eq!(tree.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_header_tokens() {
+        let tree = SyntaxTree::parse("feat(api) !: add thing", None, None);
+
+        assert_eq!(
+            tokens(&tree),
+            vec![
+                (SyntaxKind::Type, "feat"),
+                (SyntaxKind::Scope, "(api)"),
+                (SyntaxKind::Whitespace, " "),
+                (SyntaxKind::BreakingMark, "!"),
+                (SyntaxKind::Colon, ":"),
+                (SyntaxKind::Whitespace, " "),
+                (SyntaxKind::Description, "add thing"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trivia_and_footer_tokens() {
+        let input = "feat: x\n\nbody\n\nRefs #123\nKey :  value\n# comment";
+        let tree = SyntaxTree::parse(input, Some(":#"), None);
+
+        assert_eq!(
+            tokens(&tree)[4..],
+            [
+                (SyntaxKind::Newline, "\n"),
+                (SyntaxKind::Newline, "\n"),
+                (SyntaxKind::Body, "body"),
+                (SyntaxKind::Newline, "\n"),
+                (SyntaxKind::Newline, "\n"),
+                (SyntaxKind::FooterKey, "Refs"),
+                (SyntaxKind::Whitespace, " "),
+                (SyntaxKind::FooterSeparator, "#"),
+                (SyntaxKind::FooterValue, "123"),
+                (SyntaxKind::Newline, "\n"),
+                (SyntaxKind::FooterKey, "Key"),
+                (SyntaxKind::Whitespace, " "),
+                (SyntaxKind::FooterSeparator, ":"),
+                (SyntaxKind::Whitespace, "  "),
+                (SyntaxKind::FooterValue, "value"),
+                (SyntaxKind::Newline, "\n"),
+                (SyntaxKind::Comment, "# comment"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let input = "feat:  my feature.\n\nbody  with  spaces";
+        let tree = SyntaxTree::parse(input, None, None);
+        let description = tree.find(SyntaxKind::Description).next().unwrap();
+
+        let edits = [
+            Edit::delete(description.range.end - 1..description.range.end),
+            Edit::insert(4, "!"),
+            // Overlaps the first edit
+            Edit::replace(description.range.end - 1..description.range.end + 1, "x"),
+        ];
+        assert_eq!(tree.apply(&edits), "feat!:  my feature\n\nbody  with  spaces");
+    }
+
+    #[test]
+    fn test_footers() {
+        let input = "feat: x\n\nRefs #123\nKey :  value\n# comment";
+        let tree = SyntaxTree::parse(input, Some(":#"), None);
+
+        let keys: Vec<&str> = tree
+            .footers()
+            .map(|(footer, node)| {
+                assert_eq!(tree.text(node.child(SyntaxKind::FooterKey).unwrap()), footer.key);
+                footer.key.as_str()
+            })
+            .collect();
+        assert_eq!(keys, ["Refs", "Key"]);
+    }
+
+    #[test]
+    fn test_delete_line() {
+        let input = "feat: x\n\nRefs #123\nKey :  value";
+        let tree = SyntaxTree::parse(input, Some(":#"), None);
+        let (_, first) = tree.footers().next().unwrap();
+        let (_, last) = tree.footers().last().unwrap();
+
+        assert_eq!(tree.apply(&[tree.delete_line(first)]), "feat: x\n\nKey :  value");
+        assert_eq!(tree.apply(&[tree.delete_line(last)]), "feat: x\n\nRefs #123");
+    }
+
+    #[test]
+    fn test_append_footer() {
+        let footer = Footer {
+            key: "Signed-off-by".into(),
+            value: "Dev <dev@example.com>".into(),
+            separator: ':',
+            alignment: crate::SeparatorAlignment::Left,
+            span: None,
+        };
+
+        let tree = SyntaxTree::parse("feat:  x\n\nRefs: 1\n# comment\n", None, None);
+        let edit = tree.append_footer(&footer).unwrap();
+        assert_eq!(
+            tree.apply(&[edit]),
+            "feat:  x\n\nRefs: 1\nSigned-off-by: Dev <dev@example.com>\n# comment\n"
+        );
+
+        let tree = SyntaxTree::parse("feat:  x\n\nbody\n\n# comment", None, None);
+        let edit = tree.append_footer(&footer).unwrap();
+        assert_eq!(
+            tree.apply(&[edit]),
+            "feat:  x\n\nbody\n\nSigned-off-by: Dev <dev@example.com>\n\n# comment"
+        );
+
+        let tree = SyntaxTree::parse("", None, None);
+        assert!(tree.append_footer(&footer).is_none());
+    }
+}
@@ -2,7 +2,7 @@ use nom::branch::alt;
 use nom::bytes::complete::{tag, take_while1};
 use nom::character::complete::{line_ending, space0, space1};
 use nom::character::one_of;
-use nom::combinator::{all_consuming, map};
+use nom::combinator::{all_consuming, consumed, map};
 use nom::error::Error;
 use nom::multi::{fold_many1, separated_list1};
 use nom::sequence::preceded;
@@ -10,6 +10,8 @@ use nom::{IResult, Parser};
 use serde_derive::{Deserialize, Serialize};
 
 use crate::char_count;
+use crate::identity::Identity;
+use crate::span::{Position, SourceMap, Span};
 
 /// Indicates on which side of the separator the space should be
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -32,17 +34,34 @@ impl SeparatorAlignment {
 
 /// Footer represents a commit footer
 /// It consists of a key and a value separated by a separator.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, Eq, Clone)]
 pub struct Footer {
     pub key: String,
     pub value: String,
     pub separator: char,
     pub alignment: SeparatorAlignment,
+    /// Location of the footer in the parsed input.
+    /// `None` if the footer is not parsed.
+    pub span: Option<Span>,
 }
 
 impl Footer {
+    /// Breaking change key defined by the Conventional Commits specification.
     /// Keep this constant because it's exception and breaks git trailers format
-    pub(crate) const BREAKING_TAG: &'static str = "BREAKING CHANGES";
+    pub const BREAKING_TAG: &'static str = "BREAKING CHANGE";
+
+    /// Breaking change key that is a valid git trailer key
+    pub const BREAKING_TRAILER_TAG: &'static str = "BREAKING-CHANGE";
+
+    /// Plural form of the breaking change key.
+    /// It's not part of the specification, but still widely used.
+    pub(crate) const BREAKING_PLURAL_TAG: &'static str = "BREAKING CHANGES";
+
+    /// Developer Certificate of Origin sign-off key
+    pub const SIGNED_OFF_BY_KEY: &'static str = "Signed-off-by";
+
+    /// Co-author key recognized by GitHub and GitLab
+    pub const CO_AUTHORED_BY_KEY: &'static str = "Co-authored-by";
 
     /// Default key and value separator
     pub const DEFAULT_SEPARATOR: &'static str = ":";
@@ -64,20 +83,41 @@ impl Footer {
     }
 
     /// Checks if a key is a breaking change.
-    /// It is a breaking change if the key is `BREAKING CHANGES`, `Breaking-Changes`, or `BreakingChanges`.
+    /// It is a breaking change if the key is `BREAKING CHANGE` or `BREAKING-CHANGE`
+    /// from the specification, or their plural forms like `BREAKING CHANGES`,
+    /// `Breaking-Changes` and `BreakingChanges`.
     pub fn is_breaking_key(key: &str) -> bool {
-        if key == Self::BREAKING_TAG {
+        if key == Self::BREAKING_TAG || key == Self::BREAKING_PLURAL_TAG {
             return true;
         }
 
-        key.eq_ignore_ascii_case("breaking-changes")
-            || key.eq_ignore_ascii_case("breakingchanges")
+        ["breaking-change", "breaking-changes", "breakingchange", "breakingchanges"]
+            .iter()
+            .any(|variant| key.eq_ignore_ascii_case(variant))
     }
 
     pub fn is_breaking_change(&self) -> bool {
         Self::is_breaking_key(&self.key)
     }
 
+    /// Returns the identity if the value is in the `Name <email>` form,
+    /// e.g. for `Signed-off-by` and `Co-authored-by` footers.
+    pub fn identity(&self) -> Option<Identity<'_>> {
+        Identity::parse(&self.value)
+    }
+
+    /// Returns the location of the key in the parsed input.
+    pub fn key_span(&self) -> Option<Span> {
+        let start = self.span?.start;
+        let end = Position {
+            line: start.line,
+            column: start.column + char_count(&self.key),
+            offset: start.offset + self.key.len(),
+        };
+
+        Some(Span { start, end })
+    }
+
     /// Parses one footer (trailer) from the input.
     /// Returns it and the rest of the input.
     fn take(separators: &str) -> impl Parser<&str, Output = Footer, Error = Error<&str>> {
@@ -89,7 +129,7 @@ impl Footer {
                 } else {
                     SeparatorAlignment::Left
                 };
-                Self { key: key.to_string(), value, separator, alignment }
+                Self { key: key.to_string(), value, separator, alignment, span: None }
             },
         )
     }
@@ -101,7 +141,12 @@ impl Footer {
 
     /// Parse a footer key
     fn key_parser(input: &str) -> IResult<&str, &str> {
-        alt((tag(Self::BREAKING_TAG), take_while1(Self::is_valid_key_char))).parse(input)
+        alt((
+            tag(Self::BREAKING_PLURAL_TAG),
+            tag(Self::BREAKING_TAG),
+            take_while1(Self::is_valid_key_char),
+        ))
+        .parse(input)
     }
 
     /// Parse a footer value
@@ -126,6 +171,16 @@ impl Footer {
     }
 }
 
+/// Spans are not compared, so footers with the same content are equal
+impl PartialEq for Footer {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+            && self.value == other.value
+            && self.separator == other.separator
+            && self.alignment == other.alignment
+    }
+}
+
 impl std::fmt::Display for Footer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.key)?;
@@ -150,6 +205,10 @@ impl Footers {
         self.0.iter()
     }
 
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Footer> {
+        self.0.iter_mut()
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
@@ -162,6 +221,10 @@ impl Footers {
         self.0.get(index)
     }
 
+    pub fn retain(&mut self, f: impl FnMut(&Footer) -> bool) {
+        self.0.retain(f);
+    }
+
     pub fn push(&mut self, footer: Footer) {
         self.0.push(footer);
     }
@@ -173,10 +236,24 @@ impl Footers {
         separators: &'sep str,
     ) -> IResult<&'input str, Self> {
         let (rest, values) =
-            all_consuming(separated_list1(line_ending, Footer::take(separators)))
+            all_consuming(separated_list1(line_ending, consumed(Footer::take(separators))))
                 .parse(input)?;
 
-        Ok((rest, Self::from_iter(values)))
+        let source = SourceMap::new(input);
+        let footers = values.into_iter().map(|(footer_input, mut footer)| {
+            footer.span = Some(source.span_of(footer_input));
+            footer
+        });
+
+        Ok((rest, footers.collect()))
+    }
+
+    /// Moves spans of the footers, parsed from a fragment of the text,
+    /// to the text that contains this fragment at `origin`.
+    pub(crate) fn shift_spans(&mut self, origin: Position) {
+        for footer in &mut self.0 {
+            footer.span = footer.span.map(|span| span.shift(origin));
+        }
     }
 }
 
@@ -224,6 +301,7 @@ mod tests {
             value: "bar".into(),
             separator: ':',
             alignment: SeparatorAlignment::Left,
+            span: None,
         };
 
         assert_eq!(footer.len(), footer.to_string().chars().count());
@@ -233,6 +311,7 @@ mod tests {
             value: "bar\nbaz".into(),
             separator: ':',
             alignment: SeparatorAlignment::Left,
+            span: None,
         };
 
         assert_eq!(footer.len(), footer.to_string().chars().count());
@@ -242,6 +321,7 @@ mod tests {
             value: "café\n界".into(),
             separator: '→',
             alignment: SeparatorAlignment::Left,
+            span: None,
         };
 
         assert_eq!(footer.to_string(), "Réf→ café\n 界");
@@ -256,6 +336,7 @@ mod tests {
             value: String::new(),
             separator: ':',
             alignment: SeparatorAlignment::Left,
+            span: None,
         };
 
         assert!(footer.is_empty());
@@ -265,6 +346,7 @@ mod tests {
             value: String::new(),
             separator: ':',
             alignment: SeparatorAlignment::Left,
+            span: None,
         };
 
         assert!(!footer.is_empty());
@@ -278,6 +360,7 @@ mod tests {
             value: "John Doe".into(),
             separator: ':',
             alignment: SeparatorAlignment::Left,
+            span: None,
         };
 
         let (rest, footer) = Footer::take(":").parse(input).unwrap();
@@ -293,6 +376,7 @@ mod tests {
             value: "Long description\nThat even contains newlines".into(),
             separator: ':',
             alignment: SeparatorAlignment::Left,
+            span: None,
         };
 
         let (_, footer) = Footer::take(":").parse(input).unwrap();
@@ -322,11 +406,15 @@ mod tests {
 
     #[test]
     fn test_is_breaking_key() {
+        assert!(Footer::is_breaking_key("BREAKING CHANGE"));
+        assert!(Footer::is_breaking_key("BREAKING-CHANGE"));
+        assert!(Footer::is_breaking_key("Breaking-Change"));
         assert!(Footer::is_breaking_key("BREAKING CHANGES"));
         assert!(Footer::is_breaking_key("breaking-changes"));
         assert!(Footer::is_breaking_key("breakingchanges"));
         assert!(Footer::is_breaking_key("BreakingChanges"));
         assert!(!Footer::is_breaking_key("BREAKING CHANGES BUT NO"));
+        assert!(!Footer::is_breaking_key("Breaking change"));
         assert!(!Footer::is_breaking_key("not-breaking"));
         assert!(!Footer::is_breaking_key(""));
     }
@@ -350,12 +438,14 @@ mod tests {
                 value: "bar".into(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             },
             Footer {
                 key: "baz".into(),
                 value: "qux".into(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             },
         ]);
 
@@ -369,6 +459,7 @@ mod tests {
             value: "bar".into(),
             separator: ':',
             alignment: SeparatorAlignment::Left,
+            span: None,
         };
 
         assert_eq!(footer.to_string(), "foo: bar");
@@ -382,12 +473,14 @@ mod tests {
                 value: "bar".into(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             },
             Footer {
                 key: "baz".into(),
                 value: "qux".into(),
                 separator: '#',
                 alignment: SeparatorAlignment::Right,
+                span: None,
             },
         ]);
 
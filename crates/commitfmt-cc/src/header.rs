@@ -2,17 +2,23 @@ use std::fmt::Display;
 
 use nom::bytes::complete::{tag, take_while1};
 use nom::character::complete::{char, space0};
-use nom::combinator::{opt, verify};
+use nom::combinator::{consumed, opt, verify};
 use nom::multi::separated_list1;
 use nom::sequence::{delimited, preceded};
 use nom::{IResult, Parser};
 
 use crate::char_count;
+use crate::span::{SourceMap, Span};
 
 /// Scope of a commit is a list of strings
 /// Example: (scope1, scope2)
-#[derive(Debug, Default, PartialEq, Clone)]
-pub struct Scope(pub Vec<Box<str>>);
+#[derive(Debug, Default, Clone)]
+pub struct Scope {
+    /// Scopes in the order they are listed
+    pub items: Vec<Box<str>>,
+    /// Location of the scope with parentheses in the parsed header
+    pub span: Option<Span>,
+}
 
 impl Scope {
     const SEPARATOR_CHAR: char = ',';
@@ -20,7 +26,7 @@ impl Scope {
 
     /// Create a scope from an iterator
     pub fn from<I: IntoIterator<Item = T>, T: Into<Box<str>>>(iter: I) -> Self {
-        Self(iter.into_iter().map(std::convert::Into::into).collect())
+        Self { items: iter.into_iter().map(std::convert::Into::into).collect(), span: None }
     }
 
     /// Parse a list of scopes.
@@ -48,36 +54,43 @@ impl Scope {
 
     /// Returns the number of scopes
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.items.len()
     }
 
     /// Returns the number of Unicode scalar values in the formatted scopes.
     pub fn str_len(&self) -> usize {
-        if self.0.is_empty() {
+        if self.items.is_empty() {
             return 0;
         }
         let mut len: usize = 2; // parentheses
-        len += Self::SEPARATOR_DISPLAY.len() * (self.0.len() - 1); // comma and space
-        len += self.0.iter().map(|scope| char_count(scope)).sum::<usize>();
+        len += Self::SEPARATOR_DISPLAY.len() * (self.items.len() - 1); // comma and space
+        len += self.items.iter().map(|scope| char_count(scope)).sum::<usize>();
         len
     }
 
     /// Returns `true` if the are no scopes
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.items.is_empty()
     }
 
     /// Returns an iterator over the scopes
     pub fn iter(&self) -> impl Iterator<Item = &Box<str>> {
-        self.0.iter()
+        self.items.iter()
+    }
+}
+
+/// Spans are not compared, so scopes with the same items are equal
+impl PartialEq for Scope {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
     }
 }
 
 impl Display for Scope {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if !self.0.is_empty() {
+        if !self.items.is_empty() {
             write!(f, "(")?;
-            write!(f, "{}", self.0.join(Self::SEPARATOR_DISPLAY))?;
+            write!(f, "{}", self.items.join(Self::SEPARATOR_DISPLAY))?;
             write!(f, ")")?;
         }
 
@@ -86,20 +99,29 @@ impl Display for Scope {
 }
 
 /// kind(scope1,scope2)!: description
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone)]
 pub struct Header {
     pub description: String,
     pub kind: Option<String>,
     pub breaking: bool,
     pub scope: Scope,
+    /// Location of the header in the parsed input
+    pub span: Option<Span>,
+    /// Location of the kind in the parsed input
+    pub kind_span: Option<Span>,
+    /// Location of the description in the parsed input
+    pub description_span: Option<Span>,
 }
 
 impl Header {
     /// Parse a commit header
     pub fn from(input: &str) -> Self {
+        let source = SourceMap::new(input);
+        let span = Some(source.span_of(input));
+
         let Ok(result) = (
             Self::parse_kind,
-            opt(Scope::parse),
+            opt(consumed(Scope::parse)),
             Self::parse_breaking,
             Self::parse_description,
         )
@@ -110,17 +132,30 @@ impl Header {
                 scope: Scope::default(),
                 breaking: false,
                 description: input.to_string(),
+                span,
+                kind_span: None,
+                description_span: span,
             };
         };
 
         let (_, (kind, scope, breaking, description)) = result;
 
         let scope = match scope {
-            Some(scopes) => Scope(scopes),
+            Some((scope_input, items)) => {
+                Scope { items, span: Some(source.span_of(scope_input.trim_start())) }
+            }
             None => Scope::default(),
         };
 
-        Self { kind: Some(kind.to_string()), scope, breaking, description }
+        Self {
+            kind: Some(kind.to_string()),
+            scope,
+            breaking,
+            description: description.to_string(),
+            span,
+            kind_span: Some(source.span_of(kind)),
+            description_span: Some(source.span_of(description)),
+        }
     }
 
     /// Returns the number of Unicode scalar values in the formatted header.
@@ -165,10 +200,20 @@ impl Header {
     }
 
     /// Parse a commit description
-    fn parse_description(input: &str) -> IResult<&str, String> {
+    fn parse_description(input: &str) -> IResult<&str, &str> {
         preceded(preceded(space0, tag(":")), take_while1(|c: char| !c.is_control()))
             .parse(input)
-            .map(|(next_input, desc)| (next_input, desc.trim().to_string()))
+            .map(|(next_input, desc)| (next_input, desc.trim()))
+    }
+}
+
+/// Spans are not compared, so headers with the same content are equal
+impl PartialEq for Header {
+    fn eq(&self, other: &Self) -> bool {
+        self.description == other.description
+            && self.kind == other.kind
+            && self.breaking == other.breaking
+            && self.scope == other.scope
     }
 }
 
@@ -259,7 +304,7 @@ mod tests {
         let parsed = Header::from(header);
         assert_eq!(parsed.kind, Some("feat".to_string()));
         assert_eq!(parsed.scope.len(), 1);
-        assert_eq!(parsed.scope.0[0].as_ref(), "my_scope");
+        assert_eq!(parsed.scope.iter().next().unwrap().as_ref(), "my_scope");
         assert_eq!(parsed.description, "my feature");
     }
 
@@ -312,6 +357,21 @@ mod tests {
         assert_eq!(header.len(), header.to_string().chars().count());
     }
 
+    #[test]
+    fn test_header_spans() {
+        let header = Header::from("fix ( api, é )!: my fix");
+        assert_eq!(header.span.unwrap().to_string(), "1:1-1:24");
+        assert_eq!(header.kind_span.unwrap().to_string(), "1:1-1:4");
+        assert_eq!(header.scope.span.unwrap().to_string(), "1:5-1:15");
+        assert_eq!(header.description_span.unwrap().to_string(), "1:18-1:24");
+        assert_eq!(header.description_span.unwrap().range(), 18..24);
+
+        let header = Header::from("my feature");
+        assert_eq!(header.kind_span, None);
+        assert_eq!(header.scope.span, None);
+        assert_eq!(header.description_span, header.span);
+    }
+
     #[test]
     fn test_header_is_empty() {
         let header = Header::from("");
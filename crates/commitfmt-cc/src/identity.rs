@@ -0,0 +1,91 @@
+use std::fmt;
+
+/// Person identity in the `Name <email>` form.
+/// It's the value of trailers like `Signed-off-by` and `Co-authored-by`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Identity<'a> {
+    pub name: &'a str,
+    pub email: &'a str,
+}
+
+impl<'a> Identity<'a> {
+    /// Parses the `Name <email>` form.
+    /// The email syntax is not validated, use [`Identity::has_valid_email`] for that.
+    pub fn parse(value: &'a str) -> Option<Self> {
+        let value = value.trim().strip_suffix('>')?;
+        let (name, email) = value.rsplit_once('<')?;
+        let name = name.trim();
+        if name.is_empty() || name.contains(['<', '>']) {
+            return None;
+        }
+
+        Some(Self { name, email })
+    }
+
+    /// Checks that the email is `local@domain.tld` without whitespace and separators.
+    pub fn has_valid_email(&self) -> bool {
+        let Some((local, domain)) = self.email.split_once('@') else {
+            return false;
+        };
+        let is_valid_part = |part: &str| {
+            !part.is_empty()
+                && !part.chars().any(|c| c.is_whitespace() || matches!(c, '@' | ',' | ';'))
+        };
+
+        is_valid_part(local)
+            && is_valid_part(domain)
+            && domain.contains('.')
+            && domain.split('.').all(|label| !label.is_empty())
+    }
+
+    /// Checks that both identities have the same email.
+    /// Emails are compared case-insensitively.
+    pub fn same_email(&self, other: &Identity) -> bool {
+        self.email.eq_ignore_ascii_case(other.email)
+    }
+}
+
+impl fmt::Display for Identity<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} <{}>", self.name, self.email)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let identity = Identity::parse(" John Doe <john@example.com> ").unwrap();
+        assert_eq!(identity, Identity { name: "John Doe", email: "john@example.com" });
+        assert_eq!(identity.to_string(), "John Doe <john@example.com>");
+
+        assert_eq!(Identity::parse("John Doe"), None);
+        assert_eq!(Identity::parse("<john@example.com>"), None);
+        assert_eq!(Identity::parse("John <john@example.com"), None);
+        assert_eq!(Identity::parse("John <a> <john@example.com>"), None);
+    }
+
+    #[test]
+    fn test_has_valid_email() {
+        for email in ["john@example.com", "123+john@users.noreply.github.com"] {
+            assert!(Identity { name: "John", email }.has_valid_email(), "{email}");
+        }
+        for email in
+            ["", "john", "john@", "@example.com", "john@examplecom", "john@example..com"]
+        {
+            assert!(!Identity { name: "John", email }.has_valid_email(), "{email}");
+        }
+        assert!(!Identity { name: "John", email: "john doe@example.com" }.has_valid_email());
+        assert!(!Identity { name: "John", email: "john@@example.com" }.has_valid_email());
+    }
+
+    #[test]
+    fn test_same_email() {
+        let john = Identity { name: "John", email: "john@example.com" };
+        let other = Identity { name: "John Doe", email: "JOHN@example.com" };
+        assert!(john.same_email(&other));
+        assert!(!john.same_email(&Identity { name: "John", email: "jane@example.com" }));
+    }
+}
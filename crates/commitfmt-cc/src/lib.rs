@@ -1,13 +1,19 @@
+mod cst;
 pub mod footer;
 mod header;
+mod identity;
 mod message;
+mod span;
 
 pub(crate) mod body;
 
 pub use {
+    cst::{Edit, SyntaxKind, SyntaxNode, SyntaxTree},
     footer::{Footer, SeparatorAlignment},
     header::{Header, Scope},
+    identity::Identity,
     message::{Message, ParseError},
+    span::{Position, Span},
 };
 
 #[inline]
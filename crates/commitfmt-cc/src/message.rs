@@ -4,6 +4,7 @@ use thiserror::Error;
 use crate::body::{parse_body, DEFAULT_COMMENT_SYMBOL};
 use crate::footer::Footers;
 use crate::header::Header;
+use crate::span::{Position, SourceMap, Span};
 use crate::Footer;
 
 #[derive(Debug, Error)]
@@ -13,14 +14,19 @@ pub enum ParseError {
 }
 
 /// Commit message
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone)]
 pub struct Message {
     pub header: Header,
     pub body: Option<String>,
+    /// Location of the body in the parsed input
+    pub body_span: Option<Span>,
     pub footers: Footers,
 }
 
 impl Message {
+    /// Prefix of comment lines that are removed from the body
+    pub const DEFAULT_COMMENT_SYMBOL: &'static str = DEFAULT_COMMENT_SYMBOL;
+
     pub fn parse(
         input: &str,
         footer_separators: Option<&str>,
@@ -30,7 +36,12 @@ impl Message {
         let header = Header::from(&input[..header_end]);
 
         if header_end == input.len() {
-            return Message { header, body: None, footers: Footers::default() };
+            return Message {
+                header,
+                body: None,
+                body_span: None,
+                footers: Footers::default(),
+            };
         }
 
         let footer_separators = footer_separators.unwrap_or(Footer::DEFAULT_SEPARATOR);
@@ -39,7 +50,23 @@ impl Message {
         let body_input = &input[header_end + 1..];
         let (body, footers) = parse_body(body_input, footer_separators, comment_symbol);
 
-        Message { header, body, footers: footers.unwrap_or_default() }
+        let source = SourceMap::new(input);
+        let mut footers = footers.unwrap_or_default();
+        footers.shift_spans(Position { line: 2, column: 1, offset: header_end + 1 });
+
+        Message {
+            header,
+            body: body.map(str::to_string),
+            body_span: body.map(|body| source.span_of(body)),
+            footers,
+        }
+    }
+}
+
+/// Spans are not compared, so messages with the same content are equal
+impl PartialEq for Message {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header && self.body == other.body && self.footers == other.footers
     }
 }
 
@@ -78,13 +105,18 @@ Authored-By: John Doe";
                 scope: Scope::default(),
                 description: "my feature".to_string(),
                 breaking: false,
+                span: None,
+                kind_span: None,
+                description_span: None,
             },
             body: Some("Description body".to_string()),
+            body_span: None,
             footers: footer_vec![{
                 key: "Authored-By".to_string(),
                 value: "John Doe".to_string(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             }],
         };
 
@@ -102,13 +134,18 @@ Authored-By: John Doe";
                 scope: Scope::default(),
                 description: "my feature".to_string(),
                 breaking: false,
+                span: None,
+                kind_span: None,
+                description_span: None,
             },
             body: None,
+            body_span: None,
             footers: footer_vec![{
                 key: "Authored-By".to_string(),
                 value: "John Doe".to_string(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             }],
         };
 
@@ -148,14 +185,38 @@ Authored-By: John Doe";
                 scope: Scope::default(),
                 description: "rework footers config to be unified".to_string(),
                 breaking: false,
+                span: None,
+                kind_span: None,
+                description_span: None,
             },
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
         assert_eq!(parsed, expected);
     }
 
+    #[test]
+    fn test_parse_spans() {
+        let commit_msg = "feat(api): my feature\n\n\nFirst paragraph\n\nSecond paragraph\n\nAuthored-By: John Doe\nRefs #123";
+
+        let parsed = Message::parse(commit_msg, Some(":#"), None);
+
+        assert_eq!(parsed.header.span.unwrap().to_string(), "1:1-1:22");
+        assert_eq!(parsed.header.scope.span.unwrap().to_string(), "1:5-1:10");
+        assert_eq!(parsed.body_span.unwrap().to_string(), "4:1-6:17");
+        assert_eq!(&commit_msg[parsed.body_span.unwrap().range()], parsed.body.unwrap());
+
+        let footer_spans: Vec<String> =
+            parsed.footers.iter().map(|footer| footer.span.unwrap().to_string()).collect();
+        assert_eq!(footer_spans, ["8:1-8:22", "9:1-9:10"]);
+        assert_eq!(
+            &commit_msg[parsed.footers.get(1).unwrap().span.unwrap().range()],
+            "Refs #123"
+        );
+    }
+
     #[test]
     fn test_display() {
         let commit_msg = Message {
@@ -164,13 +225,18 @@ Authored-By: John Doe";
                 scope: Scope::default(),
                 description: "my feature".to_string(),
                 breaking: false,
+                span: None,
+                kind_span: None,
+                description_span: None,
             },
             body: Some("Description body".to_string()),
+            body_span: None,
             footers: footer_vec![{
                 key: "Authored-By".to_string(),
                 value: "John Doe".to_string(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             }],
         };
 
@@ -188,13 +254,18 @@ Authored-By: John Doe";
                 scope: Scope::default(),
                 description: "my feature".to_string(),
                 breaking: false,
+                span: None,
+                kind_span: None,
+                description_span: None,
             },
             body: None,
+            body_span: None,
             footers: footer_vec![{
                 key: "Authored-By".to_string(),
                 value: "John Doe".to_string(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             }],
         };
 
@@ -208,8 +279,12 @@ Authored-By: John Doe";
                 scope: Scope::default(),
                 description: "my feature".to_string(),
                 breaking: false,
+                span: None,
+                kind_span: None,
+                description_span: None,
             },
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -224,8 +299,12 @@ Authored-By: John Doe";
                 scope: Scope::default(),
                 description: "my feature".to_string(),
                 breaking: false,
+                span: None,
+                kind_span: None,
+                description_span: None,
             },
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -0,0 +1,166 @@
+use std::fmt::Display;
+use std::ops::Range;
+
+use serde_derive::Serialize;
+
+use crate::char_count;
+
+/// Position in the parsed commit message
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct Position {
+    /// Line number, starting from 1
+    pub line: usize,
+    /// Column number in Unicode scalar values, starting from 1
+    pub column: usize,
+    /// Byte offset from the start of the message
+    pub offset: usize,
+}
+
+impl Position {
+    /// Moves a position that is relative to a fragment of the text
+    /// to the text that contains this fragment at `origin`.
+    pub(crate) fn shift(self, origin: Position) -> Self {
+        let column =
+            if self.line == 1 { origin.column + self.column - 1 } else { self.column };
+
+        Self { line: origin.line + self.line - 1, column, offset: origin.offset + self.offset }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Location of a message component in the parsed input.
+/// The end position is exclusive.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    /// Returns the byte range of the span in the parsed input
+    pub fn range(&self) -> Range<usize> {
+        self.start.offset..self.end.offset
+    }
+
+    /// Returns `true` if the span does not contain any characters
+    pub fn is_empty(&self) -> bool {
+        self.start.offset == self.end.offset
+    }
+
+    /// Moves a span that is relative to a fragment of the text
+    /// to the text that contains this fragment at `origin`.
+    pub(crate) fn shift(self, origin: Position) -> Self {
+        Self { start: self.start.shift(origin), end: self.end.shift(origin) }
+    }
+
+    /// Returns the span of `fragment` within `text` located at this span.
+    /// The fragment must be a slice of `text`.
+    #[must_use]
+    pub fn sub_span(&self, text: &str, fragment: &str) -> Span {
+        SourceMap::new(text).span_of(fragment).shift(self.start)
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Maps byte offsets of the input to line and column positions
+pub(crate) struct SourceMap<'input> {
+    input: &'input str,
+    line_starts: Vec<usize>,
+}
+
+impl<'input> SourceMap<'input> {
+    pub(crate) fn new(input: &'input str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(memchr::memchr_iter(b'\n', input.as_bytes()).map(|index| index + 1))
+            .collect();
+
+        Self { input, line_starts }
+    }
+
+    /// Returns the position of the byte offset
+    pub(crate) fn position(&self, offset: usize) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let column = char_count(&self.input[line_start..offset]) + 1;
+
+        Position { line, column, offset }
+    }
+
+    /// Returns the span of the byte range
+    pub(crate) fn span(&self, range: Range<usize>) -> Span {
+        Span { start: self.position(range.start), end: self.position(range.end) }
+    }
+
+    /// Returns the span of the fragment.
+    /// The fragment must be a slice of the mapped input.
+    pub(crate) fn span_of(&self, fragment: &str) -> Span {
+        let start = fragment.as_ptr() as usize - self.input.as_ptr() as usize;
+        debug_assert!(start + fragment.len() <= self.input.len());
+
+        self.span(start..start + fragment.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let source = SourceMap::new("feat: é\n\nbody");
+
+        assert_eq!(source.position(0), Position { line: 1, column: 1, offset: 0 });
+        assert_eq!(source.position(8), Position { line: 1, column: 8, offset: 8 });
+        assert_eq!(source.position(9), Position { line: 2, column: 1, offset: 9 });
+        assert_eq!(source.position(10), Position { line: 3, column: 1, offset: 10 });
+        assert_eq!(source.position(14), Position { line: 3, column: 5, offset: 14 });
+    }
+
+    #[test]
+    fn test_span_of() {
+        let input = "feat(api): test\n\nbody";
+        let source = SourceMap::new(input);
+
+        let span = source.span_of(&input[4..9]);
+        assert_eq!(span.to_string(), "1:5-1:10");
+        assert_eq!(span.range(), 4..9);
+
+        let span = source.span_of(&input[17..]);
+        assert_eq!(span.to_string(), "3:1-3:5");
+    }
+
+    #[test]
+    fn test_shift() {
+        let origin = Position { line: 3, column: 5, offset: 20 };
+
+        let span = SourceMap::new("key: value\nnext").span(5..15);
+        assert_eq!(span.to_string(), "1:6-2:5");
+
+        let shifted = span.shift(origin);
+        assert_eq!(shifted.to_string(), "3:10-4:5");
+        assert_eq!(shifted.range(), 25..35);
+    }
+
+    #[test]
+    fn test_sub_span() {
+        let input = "feat: test\n\nfirst line\nsecond line";
+        let source = SourceMap::new(input);
+        let body = &input[12..];
+        let span = source.span_of(body);
+
+        let line = body.lines().nth(1).unwrap();
+        let sub_span = span.sub_span(body, line);
+        assert_eq!(sub_span.to_string(), "4:1-4:12");
+        assert_eq!(&input[sub_span.range()], "second line");
+    }
+}
@@ -3,7 +3,7 @@ extern crate test_generator;
 
 use std::path::PathBuf;
 
-use commitfmt_cc::{Footer, Message, Scope, SeparatorAlignment};
+use commitfmt_cc::{Footer, Message, Scope, SeparatorAlignment, SyntaxTree};
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_until};
 use nom::character::char;
@@ -167,7 +167,13 @@ impl Case {
         }
         let separator = separator_string.chars().next().unwrap();
 
-        Ok(Footer { key: key.to_string(), value: value.to_string(), alignment, separator })
+        Ok(Footer {
+            key: key.to_string(),
+            value: value.to_string(),
+            alignment,
+            separator,
+            span: None,
+        })
     }
 
     fn take_code_block(input: &str) -> IResult<&str, &str> {
@@ -214,5 +220,8 @@ fn verify_resource(resource: &str) {
         );
         assert_eq!(actual.body, expected.body, "body at {case_path}");
         assert_eq!(actual.footers, expected.footers, "footers at {case_path}");
+
+        let tree = SyntaxTree::parse(&case.input, separators, comment_symbol);
+        assert_eq!(tree.to_string(), case.input, "syntax tree at {case_path}");
     }
 }
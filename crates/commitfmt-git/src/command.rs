@@ -1,10 +1,18 @@
-use std::{path::Path, process::Command};
+use std::process::Command;
 
 use crate::{GitError, GitResult};
 
 /// Runs a git command and returns the output
-pub(crate) fn run_git(args: &[&str], dir: &Path) -> GitResult<String> {
-    let output = Command::new("git").args(args).current_dir(dir).output()?;
+#[cfg(any(test, feature = "testing"))]
+pub(crate) fn run_git(args: &[&str], dir: &std::path::Path) -> GitResult<String> {
+    let mut command = Command::new("git");
+    command.current_dir(dir);
+    run_command(command, args)
+}
+
+/// Runs the prepared git command with the arguments and returns the output
+pub(crate) fn run_command(mut command: Command, args: &[&str]) -> GitResult<String> {
+    let output = command.args(args).output()?;
     if !output.status.success() {
         return Err(GitError::CommandFailed(
             output.status.code().unwrap_or(-1),
@@ -23,6 +31,7 @@ pub(crate) fn run_git(args: &[&str], dir: &Path) -> GitResult<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::path::Path;
 
     fn get_dir() -> &'static Path {
         #[cfg(unix)]
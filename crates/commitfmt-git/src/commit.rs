@@ -1,7 +1,7 @@
 use std::io::{BufRead, BufReader, Read};
-use std::path::Path;
-use std::process::{Child, ChildStdout, Command, Stdio};
+use std::process::{Child, ChildStdout, Stdio};
 
+use crate::path::GitPaths;
 use crate::{GitError, GitResult};
 
 fn string_from_git(bytes: Vec<u8>) -> String {
@@ -11,13 +11,53 @@ fn string_from_git(bytes: Vec<u8>) -> String {
     }
 }
 
+/// Format of a commit in `git log` output.
+/// Fields are separated by NUL and commits are separated by NUL with `-z`.
+const LOG_FORMAT: &str =
+    "--format=%h%x00%H%x00%P%x00%an%x00%ae%x00%at%x00%cn%x00%ce%x00%ct%x00%B";
+
+/// Identity of a commit author or committer with the time of the action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub name: String,
+    pub email: String,
+    /// Unix timestamp in seconds
+    pub time: i64,
+}
+
+impl std::fmt::Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} <{}>", self.name, self.email)
+    }
+}
+
 /// Represents a Git commit parsed from log output.
 #[derive(Debug, PartialEq)]
 pub struct Commit {
+    /// Abbreviated sha
     pub sha: String,
+    pub full_sha: String,
+    /// Full shas of the parent commits
+    pub parents: Vec<String>,
+    pub author: Signature,
+    pub committer: Signature,
     pub message: String,
 }
 
+impl Commit {
+    /// Returns true if the commit has more than one parent
+    pub fn is_merge(&self) -> bool {
+        self.parents.len() > 1
+    }
+}
+
+/// Options of the commit history traversal.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LogOptions {
+    /// Follow only the first parent of merge commits, i.e. the mainline history
+    pub first_parent: bool,
+}
+
 /// Streaming iterator over commits produced by `git log`.
 pub struct CommitLog {
     child: Child,
@@ -26,14 +66,20 @@ pub struct CommitLog {
 }
 
 impl CommitLog {
-    pub(crate) fn spawn(dir: &Path, from: &str, to: &str) -> GitResult<Self> {
+    pub(crate) fn spawn(
+        paths: &GitPaths,
+        from: &str,
+        to: &str,
+        options: LogOptions,
+    ) -> GitResult<Self> {
         let range = format!("{from}..{to}");
-        let mut child = Command::new("git")
-            .args(["log", "-z", "--format=%h%x00%B", &range])
-            .current_dir(dir)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        let mut command = paths.git_command();
+        command.args(["log", "-z", LOG_FORMAT]);
+        if options.first_parent {
+            command.arg("--first-parent");
+        }
+        let mut child =
+            command.arg(&range).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
         let stdout = child.stdout.take().expect("stdout is configured as piped");
 
         Ok(Self { child, stdout: BufReader::new(stdout), finished: false })
@@ -50,6 +96,44 @@ impl CommitLog {
         Ok(Some(field))
     }
 
+    /// Reads a field that must be present after the sha
+    fn read_required_field(&mut self, name: &str) -> GitResult<Vec<u8>> {
+        match self.read_field()? {
+            Some(field) => Ok(field),
+            None => Err(GitError::InvalidOutput(format!("git log ended before the {name}"))),
+        }
+    }
+
+    /// Reads a signature from the name, email and timestamp fields
+    fn read_signature(&mut self, role: &str) -> GitResult<Signature> {
+        let name = string_from_git(self.read_required_field(&format!("{role} name"))?);
+        let email = string_from_git(self.read_required_field(&format!("{role} email"))?);
+        let time = string_from_git(self.read_required_field(&format!("{role} time"))?);
+        let Ok(time) = time.parse() else {
+            return Err(GitError::InvalidOutput(format!("invalid {role} time: {time}")));
+        };
+
+        Ok(Signature { name, email, time })
+    }
+
+    /// Reads the fields of a commit that follow the short sha
+    fn read_commit(&mut self, sha: Vec<u8>) -> GitResult<Commit> {
+        let full_sha = string_from_git(self.read_required_field("full sha")?);
+        let parents = string_from_git(self.read_required_field("commit parents")?);
+        let author = self.read_signature("author")?;
+        let committer = self.read_signature("committer")?;
+        let message = string_from_git(self.read_required_field("commit message")?);
+
+        Ok(Commit {
+            sha: string_from_git(sha),
+            full_sha,
+            parents: parents.split_whitespace().map(str::to_string).collect(),
+            author,
+            committer,
+            message,
+        })
+    }
+
     fn finish(&mut self) -> GitResult<()> {
         let mut stderr = Vec::new();
         if let Some(mut stream) = self.child.stderr.take() {
@@ -88,17 +172,10 @@ impl Iterator for CommitLog {
             Ok(None) => return self.finish().err().map(Err),
             Err(err) => return Some(self.fail(err)),
         };
-        let message = match self.read_field() {
-            Ok(Some(message)) => message,
-            Ok(None) => {
-                return Some(self.fail(GitError::InvalidOutput(
-                    "git log ended before the commit message".to_string(),
-                )))
-            }
-            Err(err) => return Some(self.fail(err)),
-        };
-
-        Some(Ok(Commit { sha: string_from_git(sha), message: string_from_git(message) }))
+        match self.read_commit(sha) {
+            Ok(commit) => Some(Ok(commit)),
+            Err(err) => Some(self.fail(err)),
+        }
     }
 }
 
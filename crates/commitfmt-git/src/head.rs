@@ -1,4 +1,62 @@
+use std::fs;
+use std::path::Path;
+
 const HEAD_PREFIX: &str = "ref: refs/heads/";
+const REF_PREFIX: &str = "refs/heads/";
+
+/// Files with the name of the branch that is being rebased or bisected.
+/// Paths are relative to the git directory of the work tree.
+const OPERATION_HEAD_FILES: &[(&str, Operation)] = &[
+    ("rebase-merge/head-name", Operation::Rebase),
+    ("rebase-apply/head-name", Operation::Rebase),
+    ("BISECT_START", Operation::Bisect),
+];
+
+/// Operation that detaches HEAD from the branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Rebase,
+    Bisect,
+}
+
+/// State of the work tree HEAD.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Head {
+    /// HEAD points to the branch.
+    Branch(String),
+    /// HEAD is detached by an operation on the branch, e.g. `git rebase -i`.
+    InProgress { branch: String, operation: Operation },
+    /// HEAD points to a commit outside of any branch.
+    Detached,
+}
+
+impl Head {
+    /// Returns the name of the branch the work tree is on.
+    pub fn branch_name(&self) -> Option<&str> {
+        match self {
+            Head::Branch(branch) | Head::InProgress { branch, .. } => Some(branch),
+            Head::Detached => None,
+        }
+    }
+
+    /// Reads the HEAD state from the git directory.
+    pub(crate) fn read(git_dir: &Path, head: &str) -> Head {
+        if let Some(branch) = branch_name_from_head(head) {
+            return Head::Branch(branch.to_string());
+        }
+
+        for (file, operation) in OPERATION_HEAD_FILES {
+            let Ok(content) = fs::read_to_string(git_dir.join(file)) else {
+                continue;
+            };
+            if let Some(branch) = branch_name_from_ref(content.trim()) {
+                return Head::InProgress { branch: branch.to_string(), operation: *operation };
+            }
+        }
+
+        Head::Detached
+    }
+}
 
 /// Extracts the name of the current branch from the HEAD file.
 /// Returns None if the HEAD file does not contain a branch name (e.g. detached HEAD)
@@ -10,6 +68,21 @@ pub(crate) fn branch_name_from_head(head: &str) -> Option<&str> {
     Some(head.trim_start_matches(HEAD_PREFIX).trim_end())
 }
 
+/// Extracts the branch name from the operation state file.
+/// Rebase stores the full ref or `detached HEAD`, bisect stores the short name or the sha.
+fn branch_name_from_ref(value: &str) -> Option<&str> {
+    if let Some(branch) = value.strip_prefix(REF_PREFIX) {
+        return Some(branch);
+    }
+
+    let is_sha = value.len() >= 7 && value.chars().all(|c| c.is_ascii_hexdigit());
+    if value.is_empty() || value.contains(' ') || is_sha {
+        return None;
+    }
+
+    Some(value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,4 +107,39 @@ mod tests {
         let result = branch_name_from_head(head);
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn test_branch_name_from_ref() {
+        assert_eq!(branch_name_from_ref("refs/heads/feature/ABC-1"), Some("feature/ABC-1"));
+        assert_eq!(branch_name_from_ref("main"), Some("main"));
+        assert_eq!(branch_name_from_ref("detached HEAD"), None);
+        assert_eq!(branch_name_from_ref("f1c61c8f8120c1c45031f3eb675af9dfd91dd830"), None);
+        assert_eq!(branch_name_from_ref(""), None);
+    }
+
+    #[test]
+    fn test_head_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let git_dir = dir.path();
+        let sha = "f1c61c8f8120c1c45031f3eb675af9dfd91dd830";
+
+        assert_eq!(Head::read(git_dir, "ref: refs/heads/main\n"), Head::Branch("main".into()));
+        assert_eq!(Head::read(git_dir, sha), Head::Detached);
+
+        fs::create_dir(git_dir.join("rebase-merge")).unwrap();
+        fs::write(git_dir.join("rebase-merge/head-name"), "refs/heads/feature\n").unwrap();
+        assert_eq!(
+            Head::read(git_dir, sha),
+            Head::InProgress { branch: "feature".into(), operation: Operation::Rebase }
+        );
+
+        fs::remove_dir_all(git_dir.join("rebase-merge")).unwrap();
+        fs::write(git_dir.join("BISECT_START"), "main\n").unwrap();
+        let head = Head::read(git_dir, sha);
+        assert_eq!(
+            head,
+            Head::InProgress { branch: "main".into(), operation: Operation::Bisect }
+        );
+        assert_eq!(head.branch_name(), Some("main"));
+    }
 }
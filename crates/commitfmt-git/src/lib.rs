@@ -8,8 +8,9 @@ mod repository;
 #[cfg(any(test, feature = "testing"))]
 pub mod testing;
 
-pub use commit::{Commit, CommitLog};
-pub use repository::{Repository, RepositoryConfig};
+pub use commit::{Commit, CommitLog, LogOptions, Signature};
+pub use head::{Head, Operation};
+pub use repository::{HookType, Repository, RepositoryConfig};
 
 #[derive(Error, Debug)]
 pub enum GitError {
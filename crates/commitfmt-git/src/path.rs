@@ -1,3 +1,4 @@
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -5,36 +6,105 @@ use crate::{GitError, GitResult};
 
 const COMMIT_MSG_FILE: &str = "COMMIT_EDITMSG";
 const HEAD_FILE: &str = "HEAD";
+const MERGE_HEAD_FILE: &str = "MERGE_HEAD";
+const COMMON_DIR_FILE: &str = "commondir";
+const GIT_DIR_PREFIX: &str = "gitdir:";
 
-/// Returns the path to the .git directory of a provided repo root.
-#[inline]
-pub(crate) fn git_directory(repo_dir: &Path) -> PathBuf {
-    repo_dir.join(".git")
+/// Locations of a discovered repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GitPaths {
+    /// Root of the work tree.
+    pub work_tree: PathBuf,
+    /// Git directory of the work tree, e.g. `.git/worktrees/<name>` for linked worktrees.
+    pub git_dir: PathBuf,
+    /// Git directory shared by all work trees.
+    pub common_dir: PathBuf,
+    /// Whether the paths are set with `GIT_DIR`, so git commands need them in the environment.
+    pub from_env: bool,
+}
+
+impl GitPaths {
+    /// Resolves the git directories of the work tree root.
+    /// Falls back to `<root>/.git` if the `.git` entry can't be read.
+    pub(crate) fn from_root(root_dir: &Path) -> GitPaths {
+        let git_dir =
+            resolve_git_entry(&root_dir.join(".git")).unwrap_or_else(|| root_dir.join(".git"));
+        GitPaths::new(root_dir.to_path_buf(), git_dir)
+    }
+
+    fn new(work_tree: PathBuf, git_dir: PathBuf) -> GitPaths {
+        let common_dir = common_directory(&git_dir);
+        GitPaths { work_tree, git_dir, common_dir, from_env: false }
+    }
+
+    /// Creates a git command that runs in the work tree.
+    /// Paths set with `GIT_DIR` are passed as absolute ones,
+    /// since relative values would be resolved against the work tree.
+    pub(crate) fn git_command(&self) -> Command {
+        let mut command = Command::new("git");
+        command.current_dir(&self.work_tree);
+        if self.from_env {
+            command.env("GIT_DIR", &self.git_dir).env("GIT_WORK_TREE", &self.work_tree);
+        }
+        command
+    }
 }
 
 /// Returns the path to the commit message file
 #[inline]
-pub(crate) fn get_commit_message_file(repo_dir: &Path) -> PathBuf {
-    git_directory(repo_dir).join(COMMIT_MSG_FILE)
+pub(crate) fn get_commit_message_file(git_dir: &Path) -> PathBuf {
+    git_dir.join(COMMIT_MSG_FILE)
 }
 
 /// Returns the path to the HEAD file
 #[inline]
-pub(crate) fn get_head_file(repo_dir: &Path) -> PathBuf {
-    git_directory(repo_dir).join(HEAD_FILE)
+pub(crate) fn get_head_file(git_dir: &Path) -> PathBuf {
+    git_dir.join(HEAD_FILE)
+}
+
+/// Returns the path to the `MERGE_HEAD` file
+#[inline]
+pub(crate) fn get_merge_head_file(git_dir: &Path) -> PathBuf {
+    git_dir.join(MERGE_HEAD_FILE)
 }
 
-/// Finds the root directory of a git repository.
-/// It traverses up the tree until it finds the .git directory.
-pub(crate) fn find_root(start_path: &Path) -> Option<PathBuf> {
+/// Finds the repository that contains the provided path.
+/// `GIT_DIR` and `GIT_WORK_TREE` environment variables take precedence over the lookup.
+/// Like git, it resolves their relative values against the current directory.
+pub(crate) fn find_root(start_path: &Path) -> Option<GitPaths> {
+    let from_env = |name: &str| {
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .and_then(|value| std::path::absolute(value).ok())
+    };
+
+    discover(start_path, from_env("GIT_DIR"), from_env("GIT_WORK_TREE"))
+}
+
+/// Discovers the repository with the provided `GIT_DIR` and `GIT_WORK_TREE` values.
+/// Without `GIT_DIR`, it traverses up the tree until it finds the `.git` entry,
+/// which is either a directory or a file pointing to it (worktrees and submodules).
+fn discover(
+    start_path: &Path,
+    git_dir_env: Option<PathBuf>,
+    work_tree_env: Option<PathBuf>,
+) -> Option<GitPaths> {
     let Ok(mut current) = start_path.canonicalize() else {
         return None;
     };
 
+    if let Some(git_dir) = git_dir_env.filter(|dir| !dir.as_os_str().is_empty()) {
+        let git_dir = current.join(git_dir).canonicalize().ok()?;
+        let work_tree = match work_tree_env {
+            Some(work_tree) => current.join(work_tree).canonicalize().ok()?,
+            None => current,
+        };
+        return Some(GitPaths { from_env: true, ..GitPaths::new(work_tree, git_dir) });
+    }
+
     loop {
-        let git_path = git_directory(&current);
-        if git_path.is_dir() {
-            return Some(current);
+        if let Some(git_dir) = resolve_git_entry(&current.join(".git")) {
+            return Some(GitPaths::new(current, git_dir));
         }
 
         match current.parent() {
@@ -51,19 +121,45 @@ pub(crate) fn find_root(start_path: &Path) -> Option<PathBuf> {
     None
 }
 
+/// Resolves the `.git` entry into the git directory.
+/// The entry is either the directory itself or a `gitdir: <path>` file.
+fn resolve_git_entry(entry: &Path) -> Option<PathBuf> {
+    if entry.is_dir() {
+        return Some(entry.to_path_buf());
+    }
+
+    let content = fs::read_to_string(entry).ok()?;
+    let target = content.trim().strip_prefix(GIT_DIR_PREFIX)?.trim();
+    let base = entry.parent()?;
+    let git_dir = base.join(target).canonicalize().ok()?;
+
+    git_dir.is_dir().then_some(git_dir)
+}
+
+/// Returns the directory shared by all work trees.
+/// Linked worktrees point to it with the `commondir` file.
+fn common_directory(git_dir: &Path) -> PathBuf {
+    let Ok(content) = fs::read_to_string(git_dir.join(COMMON_DIR_FILE)) else {
+        return git_dir.to_path_buf();
+    };
+
+    let common_dir = git_dir.join(content.trim());
+    common_dir.canonicalize().unwrap_or(common_dir)
+}
+
 /// Returns the path to the hooks directory
 /// It uses the `git rev-parse --git-path hooks` command so it depends
 /// on `git` executable at the $PATH.
-pub(crate) fn hooks_dir(repo_dir: &Path) -> GitResult<PathBuf> {
-    let mut cmd = Command::new("git");
-    cmd.arg("rev-parse").arg("--git-path").arg("hooks").current_dir(repo_dir);
+pub(crate) fn hooks_dir(paths: &GitPaths) -> GitResult<PathBuf> {
+    let mut cmd = paths.git_command();
+    cmd.arg("rev-parse").arg("--git-path").arg("hooks");
 
     match cmd.output() {
         Ok(output) => {
             if output.status.success() {
                 let output = String::from_utf8_lossy(&output.stdout);
                 let hook_path = PathBuf::from(output.trim());
-                return Ok(repo_dir.join(hook_path));
+                return Ok(paths.work_tree.join(hook_path));
             }
 
             let code = output.status.code().unwrap_or(-1);
@@ -80,7 +176,7 @@ mod tests {
     use std::{fs::create_dir_all, process::Command};
     use tempfile::tempdir;
 
-    use super::find_root;
+    use super::{discover, find_root, GitPaths};
 
     #[test]
     fn test_find_root() {
@@ -88,10 +184,12 @@ mod tests {
         let dir_path = dir.path();
         Command::new("git").arg("init").current_dir(dir_path).output().unwrap();
 
-        let result = find_root(dir_path);
+        let result = find_root(dir_path).unwrap();
 
-        assert!(result.is_some());
-        assert_eq!(result.unwrap().to_str(), dir_path.canonicalize().unwrap().to_str());
+        let root = dir_path.canonicalize().unwrap();
+        assert_eq!(result.git_dir, root.join(".git"));
+        assert_eq!(result.common_dir, root.join(".git"));
+        assert_eq!(result.work_tree, root);
     }
 
     #[test]
@@ -107,7 +205,69 @@ mod tests {
         let result = find_root(subdir.as_path());
 
         assert!(result.is_some());
-        assert_eq!(result.unwrap().to_str(), dir_path.canonicalize().unwrap().to_str());
+        assert_eq!(result.unwrap().work_tree, dir_path.canonicalize().unwrap());
+    }
+
+    #[test]
+    fn test_find_root_git_file() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().canonicalize().unwrap();
+        let module_dir = dir_path.join("parent/.git/modules/lib");
+        let work_tree = dir_path.join("parent/lib");
+        create_dir_all(&module_dir).unwrap();
+        create_dir_all(&work_tree).unwrap();
+        std::fs::write(work_tree.join(".git"), "gitdir: ../.git/modules/lib\n").unwrap();
+
+        let result = find_root(&work_tree).unwrap();
+
+        assert_eq!(result.work_tree, work_tree);
+        assert_eq!(result.git_dir, module_dir);
+        assert_eq!(result.common_dir, module_dir);
+    }
+
+    #[test]
+    fn test_find_root_common_dir() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().canonicalize().unwrap();
+        let common_dir = dir_path.join("main/.git");
+        let git_dir = common_dir.join("worktrees/feature");
+        let work_tree = dir_path.join("feature");
+        create_dir_all(&git_dir).unwrap();
+        create_dir_all(&work_tree).unwrap();
+        std::fs::write(git_dir.join("commondir"), "../..\n").unwrap();
+        std::fs::write(work_tree.join(".git"), format!("gitdir: {}\n", git_dir.display()))
+            .unwrap();
+
+        let result = find_root(&work_tree).unwrap();
+
+        assert_eq!(result.git_dir, git_dir);
+        assert_eq!(result.common_dir, common_dir);
+    }
+
+    #[test]
+    fn test_discover_git_dir_env() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().canonicalize().unwrap();
+        let git_dir = dir_path.join("repo.git");
+        let work_tree = dir_path.join("checkout");
+        create_dir_all(&git_dir).unwrap();
+        create_dir_all(&work_tree).unwrap();
+
+        let result =
+            discover(&dir_path, Some("repo.git".into()), Some("checkout".into())).unwrap();
+        assert_eq!(
+            result,
+            GitPaths {
+                work_tree,
+                git_dir: git_dir.clone(),
+                common_dir: git_dir.clone(),
+                from_env: true,
+            }
+        );
+
+        let result = discover(&dir_path, Some(git_dir.clone()), None).unwrap();
+        assert_eq!(result.work_tree, dir_path);
+        assert_eq!(result.git_dir, git_dir);
     }
 
     #[test]
@@ -133,7 +293,7 @@ mod tests {
             .output()
             .unwrap();
 
-        let result = hooks_dir(dir_path);
+        let result = hooks_dir(&GitPaths::from_root(dir_path));
         assert!(result.is_ok());
         assert!(result.unwrap().ends_with("hooks"));
     }
@@ -143,7 +303,7 @@ mod tests {
         let dir = tempdir().unwrap();
         let dir_path = dir.path();
 
-        let result = hooks_dir(dir_path);
+        let result = hooks_dir(&GitPaths::from_root(dir_path));
         assert!(result.is_err());
     }
 }
@@ -1,19 +1,23 @@
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use strum::Display;
 
-use crate::command::run_git;
-use crate::commit::CommitLog;
-use crate::head::branch_name_from_head;
-use crate::path::{find_root, get_commit_message_file, get_head_file, hooks_dir};
+use crate::command::run_command;
+use crate::commit::{CommitLog, LogOptions};
+use crate::head::Head;
+use crate::path::{
+    find_root, get_commit_message_file, get_head_file, get_merge_head_file, hooks_dir,
+    GitPaths,
+};
 use crate::{Commit, GitError, GitResult};
 
 const MESSAGE_CONFIG_PATTERN: &str = r"^(core\.comment(char|string)|trailer\.separators)$";
 
 #[derive(Debug, Clone)]
 pub struct Repository {
-    root_dir: PathBuf,
+    paths: GitPaths,
 }
 
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -27,6 +31,9 @@ pub enum HookType {
     #[strum(to_string = "prepare-commit-msg")]
     PrepareCommitMsg,
 
+    #[strum(to_string = "commit-msg")]
+    CommitMsg,
+
     #[strum(to_string = "applypatch-msg")]
     ApplyPatchMsg,
 }
@@ -34,38 +41,76 @@ pub enum HookType {
 impl Repository {
     /// Creates a new repository from the root directory
     pub fn from_root(root: &Path) -> Repository {
-        Repository { root_dir: root.to_path_buf() }
+        Repository::from_paths(GitPaths::from_root(root))
     }
 
-    /// Opens a repository at the given path
+    /// Opens a repository at the given path.
+    /// Linked worktrees, submodules and the `GIT_DIR` environment variable are supported.
     pub fn open(path: &Path) -> GitResult<Repository> {
         match find_root(path) {
-            Some(repo_root) => Ok(Repository::from_root(&repo_root)),
+            Some(paths) => Ok(Repository::from_paths(paths)),
             None => Err(GitError::NotFound(path.to_string_lossy().to_string())),
         }
     }
 
+    fn from_paths(paths: GitPaths) -> Repository {
+        Repository { paths }
+    }
+
     /// Returns the root directory of the repository
     pub fn get_root(&self) -> PathBuf {
-        self.root_dir.clone()
+        self.paths.work_tree.clone()
+    }
+
+    /// Returns the git directory of the work tree
+    pub fn git_dir(&self) -> &Path {
+        &self.paths.git_dir
     }
 
-    /// Returns the name of the current branch
+    /// Returns the git directory shared by all work trees
+    pub fn common_dir(&self) -> &Path {
+        &self.paths.common_dir
+    }
+
+    /// Returns the name of the current branch.
+    /// The branch is resolved during rebase and bisect, when HEAD is detached.
     pub fn get_branch_name(&self) -> Option<String> {
-        let head_path = get_head_file(&self.root_dir);
-        let Ok(head) = fs::read_to_string(head_path) else {
-            return None;
-        };
+        self.head().ok()?.branch_name().map(std::string::ToString::to_string)
+    }
 
-        branch_name_from_head(&head).map(std::string::ToString::to_string)
+    /// Returns the state of HEAD
+    pub fn head(&self) -> GitResult<Head> {
+        let head = fs::read_to_string(get_head_file(&self.paths.git_dir))?;
+        Ok(Head::read(&self.paths.git_dir, &head))
     }
 
     /// Returns true if a commit is in progress
     pub fn is_committing(&self) -> bool {
-        let msg_path = get_commit_message_file(&self.root_dir);
+        let msg_path = get_commit_message_file(&self.paths.git_dir);
         fs::metadata(msg_path).is_ok()
     }
 
+    /// Returns the shas of the commits being merged into HEAD.
+    /// The list is empty if no merge is in progress.
+    pub fn merge_heads(&self) -> Vec<String> {
+        let Ok(content) = fs::read_to_string(get_merge_head_file(&self.paths.git_dir)) else {
+            return Vec::new();
+        };
+
+        content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Returns the author identity of the next commit in the `Name <email>` form
+    pub fn author_ident(&self) -> Option<String> {
+        let ident = self.run(&["var", "GIT_AUTHOR_IDENT"]).ok()?;
+        author_from_ident(&ident)
+    }
+
     /// Returns the commits between two references
     pub fn get_log(&self, from: &str, to: &str) -> GitResult<Vec<Commit>> {
         self.stream_log(from, to)?.collect()
@@ -73,7 +118,23 @@ impl Repository {
 
     /// Streams commits between two references.
     pub fn stream_log(&self, from: &str, to: &str) -> GitResult<CommitLog> {
-        CommitLog::spawn(&self.root_dir, from, to)
+        self.stream_log_with(from, to, LogOptions::default())
+    }
+
+    /// Streams commits between two references with the traversal options.
+    pub fn stream_log_with(
+        &self,
+        from: &str,
+        to: &str,
+        options: LogOptions,
+    ) -> GitResult<CommitLog> {
+        CommitLog::spawn(&self.paths, from, to, options)
+    }
+
+    /// Returns the full shas of the commits reachable from any reference
+    pub fn reachable_commits(&self) -> GitResult<HashSet<String>> {
+        let output = self.run(&["rev-list", "--all"])?;
+        Ok(output.lines().map(str::to_string).collect())
     }
 
     pub fn commit(&self, message: &str) -> GitResult<()> {
@@ -83,19 +144,19 @@ impl Repository {
 
     /// Reads the commit message
     pub fn read_commit_message(&self) -> GitResult<String> {
-        let msg_path = get_commit_message_file(&self.root_dir);
+        let msg_path = get_commit_message_file(&self.paths.git_dir);
         fs::read_to_string(msg_path).map_err(GitError::IOError)
     }
 
     /// Writes the commit message
     pub fn write_commit_message(&self, msg: &str) -> GitResult<()> {
-        let msg_path = get_commit_message_file(&self.root_dir);
+        let msg_path = get_commit_message_file(&self.paths.git_dir);
         fs::write(msg_path, msg).map_err(GitError::IOError)
     }
 
     /// Returns the path to the hook
     pub fn hook_path(&self, hook: HookType) -> GitResult<PathBuf> {
-        let hooks_path = hooks_dir(&self.get_root())?;
+        let hooks_path = hooks_dir(&self.paths)?;
         Ok(hooks_path.join(hook.to_string()))
     }
 
@@ -110,10 +171,17 @@ impl Repository {
 
     /// Runs a git command in the repository
     pub(crate) fn run(&self, args: &[&str]) -> GitResult<String> {
-        run_git(args, &self.root_dir)
+        run_command(self.paths.git_command(), args)
     }
 }
 
+/// Strips the timestamp and timezone from the git ident, e.g.
+/// `Name <email> 1700000000 +0000`
+fn author_from_ident(ident: &str) -> Option<String> {
+    let end = ident.rfind('>')?;
+    Some(ident[..=end].to_string())
+}
+
 fn parse_message_config(output: &str) -> RepositoryConfig {
     let mut comment_char = None;
     let mut comment_string = None;
@@ -139,6 +207,7 @@ fn parse_message_config(output: &str) -> RepositoryConfig {
 #[cfg(test)]
 mod tests {
     use crate::testing::TestBed;
+    use crate::{Operation, Signature};
 
     use super::*;
     use std::fs;
@@ -169,6 +238,24 @@ mod tests {
         assert!(matches!(result.unwrap_err(), GitError::NotFound(_)));
     }
 
+    #[test]
+    fn test_git_dir_env_commands() {
+        let test_bed = TestBed::empty().unwrap();
+        test_bed.repo.commit("feat: initial").unwrap();
+        let work_tree = tempdir().unwrap();
+        let git_dir = test_bed.repo.git_dir().to_path_buf();
+
+        let repo = Repository::from_paths(GitPaths {
+            work_tree: work_tree.path().to_path_buf(),
+            git_dir: git_dir.clone(),
+            common_dir: git_dir,
+            from_env: true,
+        });
+
+        assert_eq!(repo.reachable_commits().unwrap().len(), 1);
+        assert!(repo.hook_path(HookType::CommitMsg).unwrap().starts_with(repo.git_dir()));
+    }
+
     #[test]
     fn test_get_branch_name() {
         let test_bed = TestBed::empty().unwrap();
@@ -213,6 +300,56 @@ mod tests {
         assert!(test_bed.repo.is_committing());
     }
 
+    #[test]
+    fn test_open_linked_worktree() {
+        let test_bed = TestBed::with_default_history().unwrap();
+        let worktree_dir = tempdir().unwrap();
+        let worktree_path = worktree_dir.path().join("feature");
+        let worktree_arg = worktree_path.to_string_lossy();
+        test_bed.repo.run(&["worktree", "add", "-b", "feature", &worktree_arg]).unwrap();
+
+        let repo = Repository::open(&worktree_path).unwrap();
+        let common_dir = test_bed.path().canonicalize().unwrap().join(".git");
+        assert_eq!(repo.get_root(), worktree_path.canonicalize().unwrap());
+        assert_eq!(repo.git_dir(), common_dir.join("worktrees/feature"));
+        assert_eq!(repo.common_dir(), common_dir);
+        assert_eq!(repo.get_branch_name(), Some("feature".to_string()));
+
+        repo.write_commit_message("feat: worktree").unwrap();
+        assert_eq!(repo.read_commit_message().unwrap(), "feat: worktree");
+        assert_ne!(test_bed.repo.read_commit_message().unwrap(), "feat: worktree");
+        assert!(repo
+            .hook_path(HookType::CommitMsg)
+            .unwrap()
+            .starts_with(common_dir.join("hooks")));
+    }
+
+    #[test]
+    fn test_head_during_rebase() {
+        let test_bed = TestBed::with_default_history().unwrap();
+        let repo = &test_bed.repo;
+        assert_eq!(repo.head().unwrap(), Head::Branch("main".to_string()));
+
+        repo.run(&[
+            "-c",
+            "sequence.editor=perl -pi -e 's/^pick/edit/ if $. == 1'",
+            "rebase",
+            "-i",
+            "HEAD~2",
+        ])
+        .unwrap();
+        assert_eq!(
+            repo.head().unwrap(),
+            Head::InProgress { branch: "main".to_string(), operation: Operation::Rebase }
+        );
+        assert_eq!(repo.get_branch_name(), Some("main".to_string()));
+
+        repo.run(&["rebase", "--abort"]).unwrap();
+        repo.run(&["switch", "--detach", "HEAD~1"]).unwrap();
+        assert_eq!(repo.head().unwrap(), Head::Detached);
+        assert_eq!(repo.get_branch_name(), None);
+    }
+
     #[test]
     fn test_hook_path() {
         let test_bed = TestBed::empty().unwrap();
@@ -260,6 +397,125 @@ mod tests {
         assert_eq!(log[0].message.trim_end(), message);
     }
 
+    #[test]
+    fn test_get_log_metadata() {
+        let test_bed = TestBed::with_history(&["chore: initial commit"]).unwrap();
+        test_bed.switch_to_new("feature").unwrap();
+        test_bed.repo.commit("feat: feature").unwrap();
+        test_bed.switch(TestBed::DEFAULT_BRANCH_NAME).unwrap();
+        test_bed.repo.commit("fix: main").unwrap();
+        test_bed.merge("feature", "Merge branch 'feature'").unwrap();
+
+        let log = test_bed.repo.get_log("HEAD~1", "HEAD").unwrap();
+        let merge = log.iter().find(|commit| commit.message.starts_with("Merge")).unwrap();
+        assert_eq!(merge.parents.len(), 2);
+        assert!(merge.is_merge());
+        assert_eq!(merge.author.to_string(), "Test User <test@example.com>");
+
+        let commit = log.iter().find(|commit| commit.message.starts_with("feat")).unwrap();
+        assert_eq!(commit.parents.len(), 1);
+        assert!(!commit.is_merge());
+    }
+
+    #[test]
+    fn test_get_log_signatures() {
+        let test_bed = TestBed::with_history(&["chore: initial commit"]).unwrap();
+        test_bed
+            .repo
+            .run(&[
+                "-c",
+                "user.name=Committer",
+                "-c",
+                "user.email=committer@example.com",
+                "commit",
+                "--allow-empty",
+                "--message",
+                "feat: test",
+                "--author",
+                "Author <author@example.com>",
+                "--date",
+                "1700000000 +0000",
+            ])
+            .unwrap();
+        let head = test_bed.repo.run(&["rev-parse", "HEAD"]).unwrap();
+        let parent = test_bed.repo.run(&["rev-parse", "HEAD~1"]).unwrap();
+
+        let log = test_bed.repo.get_log("HEAD~1", "HEAD").unwrap();
+        let commit = &log[0];
+        assert_eq!(commit.full_sha, head);
+        assert!(head.starts_with(&commit.sha));
+        assert_eq!(commit.parents, vec![parent]);
+        assert_eq!(
+            commit.author,
+            Signature {
+                name: "Author".to_string(),
+                email: "author@example.com".to_string(),
+                time: 1_700_000_000,
+            }
+        );
+        assert_eq!(commit.committer.to_string(), "Committer <committer@example.com>");
+        assert!(commit.committer.time > commit.author.time);
+        assert_eq!(commit.message, "feat: test\n");
+    }
+
+    #[test]
+    fn test_stream_log_first_parent() {
+        let test_bed = TestBed::with_history(&["chore: initial commit"]).unwrap();
+        test_bed.switch_to_new("feature").unwrap();
+        test_bed.repo.commit("feat: feature").unwrap();
+        test_bed.switch(TestBed::DEFAULT_BRANCH_NAME).unwrap();
+        test_bed.repo.commit("fix: main").unwrap();
+        test_bed.merge("feature", "Merge branch 'feature'").unwrap();
+
+        let options = LogOptions { first_parent: true };
+        let log: Vec<Commit> = test_bed
+            .repo
+            .stream_log_with("HEAD~2", "HEAD", options)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        let messages: Vec<&str> = log.iter().map(|commit| commit.message.trim_end()).collect();
+        assert_eq!(messages, vec!["Merge branch 'feature'", "fix: main"]);
+
+        assert_eq!(test_bed.repo.get_log("HEAD~2", "HEAD").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_reachable_commits() {
+        let test_bed = TestBed::with_history(&["feat: first", "feat: second"]).unwrap();
+        let log = test_bed.repo.get_log("HEAD~1", "HEAD").unwrap();
+
+        let commits = test_bed.repo.reachable_commits().unwrap();
+        assert_eq!(commits.len(), 2);
+        assert!(commits.contains(&log[0].full_sha));
+        assert!(TestBed::empty().unwrap().repo.reachable_commits().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_merge_heads() {
+        let test_bed = TestBed::with_history(&["chore: initial commit"]).unwrap();
+        assert!(test_bed.repo.merge_heads().is_empty());
+
+        let sha = "0123456789abcdef0123456789abcdef01234567";
+        fs::write(test_bed.path().join(".git/MERGE_HEAD"), format!("{sha}\n")).unwrap();
+        assert_eq!(test_bed.repo.merge_heads(), vec![sha.to_string()]);
+    }
+
+    #[test]
+    fn test_author_ident() {
+        let test_bed = TestBed::empty().unwrap();
+        assert_eq!(
+            test_bed.repo.author_ident().as_deref(),
+            Some("Test User <test@example.com>")
+        );
+
+        assert_eq!(
+            author_from_ident("Name <name@example.com> 1700000000 +0000").as_deref(),
+            Some("Name <name@example.com>")
+        );
+        assert_eq!(author_from_ident("invalid"), None);
+    }
+
     #[test]
     fn test_stream_log_reports_git_error() {
         let test_bed = TestBed::with_default_history().unwrap();
@@ -309,6 +565,7 @@ mod tests {
     #[test]
     fn test_hook_type_display() {
         assert_eq!(HookType::PrepareCommitMsg.to_string(), "prepare-commit-msg");
+        assert_eq!(HookType::CommitMsg.to_string(), "commit-msg");
         assert_eq!(HookType::ApplyPatchMsg.to_string(), "applypatch-msg");
     }
 }
@@ -66,6 +66,18 @@ impl TestBed {
         Ok(())
     }
 
+    /// Switches to an existing branch
+    pub fn switch(&self, branch_name: &str) -> GitResult<()> {
+        run_git(&["switch", branch_name], self.dir.path())?;
+        Ok(())
+    }
+
+    /// Merges the branch into the current one with a merge commit
+    pub fn merge(&self, branch_name: &str, message: &str) -> GitResult<()> {
+        run_git(&["merge", "--no-ff", "--message", message, branch_name], self.dir.path())?;
+        Ok(())
+    }
+
     pub fn with_default_history() -> GitResult<Self> {
         let test_bed = Self::empty()?;
         for message in COMMIT_HISTORY {
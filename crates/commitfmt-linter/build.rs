@@ -3,7 +3,7 @@ use std::env;
 use std::fmt::Write;
 use std::fs::{self, create_dir_all};
 use std::path::Path;
-use syn::{File, Item};
+use syn::{File, Item, ItemStruct};
 use walkdir::WalkDir;
 
 #[derive(Clone)]
@@ -49,6 +49,22 @@ fn main() {
 
     // Generate index file
     generate_index_file(&docs_dir, &rules_by_group);
+
+    // Generate fix modes table
+    let out_dir = env::var("OUT_DIR").unwrap();
+    generate_fix_modes_file(Path::new(&out_dir), &rules_by_group);
+}
+
+/// Checks that the struct is a rule violation, i.e. derives `ViolationMetadata`.
+fn is_violation(item: &ItemStruct) -> bool {
+    item.attrs.iter().filter(|attr| attr.path().is_ident("derive")).any(|attr| {
+        let mut found = false;
+        let _ = attr.parse_nested_meta(|meta| {
+            found |= meta.path.is_ident("ViolationMetadata");
+            Ok(())
+        });
+        found
+    })
 }
 
 fn process_file(
@@ -75,6 +91,9 @@ fn process_file(
 
     for item in syntax.items {
         if let Item::Struct(s) = item {
+            if !is_violation(&s) {
+                continue;
+            }
             let struct_name = s.ident.to_string();
 
             // Skip if we've already processed this file+struct
@@ -239,3 +258,25 @@ fn generate_index_file(docs_dir: &Path, rules_by_group: &HashMap<String, Vec<Rul
 
     fs::write(docs_dir.join("rules.md"), content).unwrap();
 }
+
+/// Writes the `(group, rule, fix mode)` table that is included by `Rule::fix_mode`.
+fn generate_fix_modes_file(out_dir: &Path, rules_by_group: &HashMap<String, Vec<RuleInfo>>) {
+    let mut entries: Vec<(&String, &RuleInfo)> = rules_by_group
+        .iter()
+        .flat_map(|(group, rules)| rules.iter().map(move |rule| (group, rule)))
+        .collect();
+    entries.sort_by(|a, b| (a.0, &a.1.rule_name).cmp(&(b.0, &b.1.rule_name)));
+
+    let mut content = String::from("&[\n");
+    for (group, rule_info) in entries {
+        writeln!(
+            content,
+            "    (\"{group}\", \"{}\", FixMode::{}),",
+            rule_info.rule_name, rule_info.fix_mode
+        )
+        .unwrap();
+    }
+    content.push_str("]\n");
+
+    fs::write(out_dir.join("fix_modes.rs"), content).unwrap();
+}
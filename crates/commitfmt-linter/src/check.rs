@@ -2,18 +2,47 @@ use commitfmt_cc::Message;
 
 use crate::report::Report;
 use crate::rule_set::RuleSet;
-use crate::rules::{body, footer, header, Rule, Settings};
+use crate::rules::{body, footer, header, Rule, Settings, TypeOverride};
 
 pub struct Check<'a> {
     pub report: Report,
 
     settings: &'a Settings,
     rules: RuleSet,
+    warnings: RuleSet,
+    overrides: &'a [TypeOverride],
+    author: Option<String>,
 }
 
 impl<'a> Check<'a> {
     pub fn new(settings: &'a Settings, rules: RuleSet) -> Self {
-        Self { report: Report::default(), settings, rules }
+        Self {
+            report: Report::default(),
+            settings,
+            rules,
+            warnings: RuleSet::empty(),
+            overrides: &[],
+            author: None,
+        }
+    }
+
+    /// Sets the rules whose violations are reported as warnings.
+    #[must_use]
+    pub fn with_warnings(mut self, warnings: RuleSet) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    /// Sets the rules that replace the base ones for messages of the commit type.
+    #[must_use]
+    pub fn with_overrides(mut self, overrides: &'a [TypeOverride]) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Sets the author of the checked commit in the `Name <email>` form.
+    pub fn set_author(&mut self, author: Option<String>) {
+        self.author = author;
     }
 
     fn lint_header_type(&mut self, message: &Message) {
@@ -64,7 +93,11 @@ impl<'a> Check<'a> {
             header::scope_enum(&mut self.report, message, &self.settings.header.scope_enum);
         }
         if self.rules.contains(Rule::HeaderScopeRequired) {
-            header::scope_required(&mut self.report, message);
+            header::scope_required(
+                &mut self.report,
+                message,
+                &self.settings.header.scope_required,
+            );
         }
     }
 
@@ -151,9 +184,44 @@ impl<'a> Check<'a> {
         if self.rules.contains(Rule::FooterMinLength) {
             footer::min_length(&mut self.report, message, self.settings.footer.min_length);
         }
+        if self.rules.contains(Rule::FooterKeyEnum) {
+            footer::key_enum(
+                &mut self.report,
+                message,
+                &self.settings.footer.key_enum,
+                &self.settings.footer.key_aliases,
+            );
+        }
+        if self.rules.contains(Rule::FooterValuePattern) {
+            footer::value_pattern(
+                &mut self.report,
+                message,
+                &self.settings.footer.value_pattern,
+            );
+        }
+        if self.rules.contains(Rule::FooterCoAuthoredBy) {
+            footer::co_authored_by(&mut self.report, message, self.settings.footer.co_author);
+        }
     }
 
     pub fn lint(&mut self, message: &Message) {
+        let kind = message.header.kind.as_deref();
+        let Some(type_override) =
+            self.overrides.iter().find(|type_override| Some(&*type_override.kind) == kind)
+        else {
+            self.report.warnings = self.warnings;
+            self.lint_message(message);
+            return;
+        };
+
+        let base = (self.settings, self.rules);
+        (self.settings, self.rules) = (&type_override.settings, type_override.set);
+        self.report.warnings = type_override.warnings;
+        self.lint_message(message);
+        (self.settings, self.rules) = base;
+    }
+
+    fn lint_message(&mut self, message: &Message) {
         self.lint_header(message);
 
         if message.body.is_some() {
@@ -165,6 +233,14 @@ impl<'a> Check<'a> {
         if self.rules.contains(Rule::FooterExists) {
             footer::exists(&mut self.report, message, &self.settings.footer.required);
         }
+        if self.rules.contains(Rule::FooterSignedOffBy) {
+            footer::signed_off_by(
+                &mut self.report,
+                message,
+                self.settings.footer.sign_off,
+                self.author.as_deref(),
+            );
+        }
         if !message.footers.is_empty() {
             self.lint_footers(message);
         }
@@ -184,7 +260,12 @@ impl std::fmt::Display for Check<'_> {
 mod tests {
     use commitfmt_cc::Message;
 
-    use crate::{check::Check, rule_set::RuleSet, rules::Settings};
+    use crate::{
+        check::Check,
+        rule_set::RuleSet,
+        rules::{Rule, Settings, TypeOverride},
+        violation::Severity,
+    };
 
     #[test]
     fn test_check_default() {
@@ -202,4 +283,45 @@ mod tests {
 
         check.lint(&message);
     }
+
+    #[test]
+    fn test_check_type_override() {
+        let settings = Settings::default();
+        let mut fix_settings = Settings::default();
+        fix_settings.footer.required = vec!["Refs".into()];
+        let overrides = [TypeOverride {
+            kind: "fix".into(),
+            set: RuleSet::default().union(RuleSet::from_rules(&[Rule::FooterExists])),
+            warnings: RuleSet::empty(),
+            settings: fix_settings,
+        }];
+        let mut check = Check::new(&settings, RuleSet::default()).with_overrides(&overrides);
+
+        check.lint(&Message::parse("feat: test", None, None));
+        assert!(check.report.is_empty());
+
+        check.lint(&Message::parse("fix: test", None, None));
+        assert_eq!(check.report.len(), 1);
+        assert_eq!(check.report.violations[0].rule_name(), "Exists");
+
+        check.lint(&Message::parse("feat: test", None, None));
+        assert_eq!(check.report.len(), 1);
+    }
+
+    #[test]
+    fn test_check_warnings() {
+        let settings = Settings::default();
+        let rules = RuleSet::default();
+        let warnings = RuleSet::from_rules(&[Rule::HeaderDescriptionFullStop]);
+        let mut check = Check::new(&settings, rules).with_warnings(warnings);
+
+        check.lint(&Message::parse("feat: test.", None, None));
+        check.lint(&Message::parse("feat: test", None, None));
+        assert_eq!(check.report.len(), 1);
+        assert_eq!(check.report.severity(0), Severity::Warning);
+
+        let mut check = Check::new(&settings, rules);
+        check.lint(&Message::parse("feat: test.", None, None));
+        assert_eq!(check.report.severity(0), Severity::Error);
+    }
 }
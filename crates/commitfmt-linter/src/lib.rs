@@ -9,4 +9,4 @@ pub mod violation;
 pub use check::Check;
 pub use rule_set::RuleSet;
 pub use rules::Rule;
-pub use violation::{FixMode, Violation};
+pub use violation::{FixMode, Severity, Violation};
@@ -1,16 +1,43 @@
-use crate::violation::Violation;
+use std::iter;
+
+use crate::rule_set::RuleSet;
+use crate::rules::Rule;
+use crate::violation::{Severity, Violation};
 
-#[derive(Default)]
 pub struct Report {
     pub violations: Vec<Box<dyn Violation>>,
+    /// Severity of each violation, in the same order
+    severities: Vec<Severity>,
+    /// Rules whose violations are added as warnings
+    pub(crate) warnings: RuleSet,
+}
+
+impl Default for Report {
+    fn default() -> Self {
+        Self { violations: Vec::new(), severities: Vec::new(), warnings: RuleSet::empty() }
+    }
 }
 
 impl Report {
     /// Adds a violation
     pub fn add_violation(&mut self, violation: Box<dyn Violation>) {
+        let is_warning = Rule::from_violation(violation.as_ref())
+            .is_some_and(|rule| self.warnings.contains(rule));
+        self.severities.push(if is_warning { Severity::Warning } else { Severity::Error });
         self.violations.push(violation);
     }
 
+    /// Returns the severity of the violation at `index`
+    pub fn severity(&self, index: usize) -> Severity {
+        self.severities.get(index).copied().unwrap_or_default()
+    }
+
+    /// Consumes the report and returns the violations with their severities
+    pub fn into_violations(self) -> impl Iterator<Item = (Box<dyn Violation>, Severity)> {
+        let severities = self.severities.into_iter().chain(iter::repeat(Severity::Error));
+        self.violations.into_iter().zip(severities)
+    }
+
     /// Returns the number of violations
     pub fn len(&self) -> usize {
         self.violations.len()
@@ -24,6 +51,7 @@ impl Report {
     /// Clears the violations
     pub fn clear(&mut self) {
         self.violations.clear();
+        self.severities.clear();
     }
 }
 
@@ -49,6 +77,18 @@ mod tests {
         assert!(!report.is_empty());
     }
 
+    #[test]
+    fn test_report_severity() {
+        let mut report = Report::default();
+        report.add_violation(Box::new(TestViolation));
+        assert_eq!(report.severity(0), Severity::Error);
+        assert_eq!(report.severity(1), Severity::Error);
+
+        let violations: Vec<_> = report.into_violations().collect();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].1, Severity::Error);
+    }
+
     #[test]
     fn test_report_clear() {
         let mut report = Report::default();
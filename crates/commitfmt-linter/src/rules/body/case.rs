@@ -1,4 +1,4 @@
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 use crate::case::TextCase;
@@ -28,6 +28,7 @@ use crate::violation::{Violation, ViolationMetadata};
 #[derive(ViolationMetadata)]
 pub(crate) struct Case {
     case: TextCase,
+    span: Option<Span>,
 }
 
 impl Violation for Case {
@@ -39,6 +40,10 @@ impl Violation for Case {
         let case = self.case;
         format!("Body case is inconsistent. Expected: {case}")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks that the character case of the commit body is consistent
@@ -48,7 +53,7 @@ pub(crate) fn case(report: &mut Report, message: &Message, case: TextCase) {
     };
 
     if !case.is_match(body) {
-        let violation = Box::new(Case { case });
+        let violation = Box::new(Case { case, span: message.body_span });
         report.add_violation(violation);
     }
 }
@@ -66,6 +71,7 @@ mod tests {
         let mut message: Message = Message {
             header: Header::from("feat: my feature"),
             body: Some("feature description".to_string()),
+            body_span: None,
             footers: footer_vec![],
         };
 
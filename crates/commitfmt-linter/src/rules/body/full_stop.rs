@@ -1,4 +1,4 @@
-use commitfmt_cc::Message;
+use commitfmt_cc::{Edit, Message, Span, SyntaxKind, SyntaxTree};
 use commitfmt_macros::ViolationMetadata;
 
 use crate::report::Report;
@@ -26,7 +26,9 @@ use crate::violation::{FixMode, Violation, ViolationError, ViolationMetadata};
 /// My feature is so cool. I can't even describe it.
 /// ```
 #[derive(ViolationMetadata)]
-pub(crate) struct FullStop;
+pub(crate) struct FullStop {
+    span: Option<Span>,
+}
 
 impl Violation for FullStop {
     fn group(&self) -> LinterGroup {
@@ -46,10 +48,19 @@ impl Violation for FullStop {
         Ok(())
     }
 
+    fn edits(&self, tree: &SyntaxTree) -> Option<Vec<Edit>> {
+        let body = tree.find(SyntaxKind::Body).next()?;
+        Some(vec![Edit::insert(body.range.end, ".")])
+    }
+
     #[allow(clippy::useless_format)]
     fn message(&self) -> String {
         format!("Body is not ended with a full stop")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for body ending with full stop
@@ -59,7 +70,7 @@ pub(crate) fn full_stop(report: &mut Report, message: &Message) {
     };
 
     if !body.ends_with('.') {
-        let violation = Box::new(FullStop);
+        let violation = Box::new(FullStop { span: message.body_span });
         report.add_violation(violation);
     }
 }
@@ -77,6 +88,7 @@ mod tests {
         let mut message: Message = Message {
             header: Header::from("feat: my feature"),
             body: Some("\nDescription.".to_string()),
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -95,6 +107,7 @@ mod tests {
         let mut message: Message = Message {
             header: Header::from("feat: my feature"),
             body: Some("\nDescription".to_string()),
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -103,4 +116,17 @@ mod tests {
 
         assert_eq!(message.body, Some("\nDescription.".to_string()));
     }
+
+    #[test]
+    fn test_edits() {
+        let input = "feat:  my feature\n\nDescription  \n\n# comment";
+        let tree = SyntaxTree::parse(input, None, None);
+
+        let mut report = Report::default();
+        full_stop(&mut report, tree.message());
+        assert_eq!(report.len(), 1);
+
+        let edits = report.violations[0].edits(&tree).unwrap();
+        assert_eq!(tree.apply(&edits), "feat:  my feature\n\nDescription.  \n\n# comment");
+    }
 }
@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::{longer_than_chars, LinterGroup};
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -26,6 +26,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct MaxLength {
     pub(crate) max_length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for MaxLength {
@@ -37,6 +38,10 @@ impl Violation for MaxLength {
         let max_length = self.max_length;
         format!("Body is longer than {max_length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for long body
@@ -49,7 +54,7 @@ pub(crate) fn max_length(report: &mut Report, message: &Message, length: usize)
     };
 
     if longer_than_chars(body, length) {
-        let violation = Box::new(MaxLength { max_length: length });
+        let violation = Box::new(MaxLength { max_length: length, span: message.body_span });
         report.add_violation(violation);
     }
 }
@@ -67,6 +72,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: Some("\nBody with some text".to_string()),
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::{longer_than_chars, LinterGroup};
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -27,6 +27,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct MaxLineLength {
     pub(crate) max_length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for MaxLineLength {
@@ -38,6 +39,10 @@ impl Violation for MaxLineLength {
         let max_length = self.max_length;
         format!("Body line is longer than {max_length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for long body lines
@@ -50,7 +55,8 @@ pub(crate) fn max_line_length(report: &mut Report, message: &Message, max_length
     };
     for line in body.lines() {
         if longer_than_chars(line, max_length) {
-            let violation = Box::new(MaxLineLength { max_length });
+            let span = message.body_span.map(|span| span.sub_span(body, line));
+            let violation = Box::new(MaxLineLength { max_length, span });
             report.add_violation(violation);
             return;
         }
@@ -70,6 +76,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: Some("\nBody\nWith some text".to_string()),
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -80,4 +87,14 @@ mod tests {
         assert_eq!(report.len(), 1);
         assert_eq!(report.violations[0].rule_name(), "MaxLineLength");
     }
+
+    #[test]
+    fn test_max_line_length_span() {
+        let mut report = Report::default();
+        let message = Message::parse("feat: my feature\n\nBody\nWith some text", None, None);
+
+        max_line_length(&mut report, &message, 5);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.violations[0].span().unwrap().to_string(), "4:1-4:15");
+    }
 }
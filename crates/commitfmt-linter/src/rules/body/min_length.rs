@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::{shorter_than_chars, LinterGroup};
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -24,6 +24,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct MinLength {
     pub(crate) length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for MinLength {
@@ -35,6 +36,10 @@ impl Violation for MinLength {
         let length = self.length;
         format!("Body is shorter than {length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for short body
@@ -42,7 +47,7 @@ pub(crate) fn min_length(report: &mut Report, message: &Message, length: usize)
     if length == 0 {
         return;
     }
-    let violation = Box::new(MinLength { length });
+    let violation = Box::new(MinLength { length, span: message.body_span });
 
     let Some(body) = message.body.as_ref() else {
         report.add_violation(violation);
@@ -67,6 +72,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: Some("\nBody with some text".to_string()),
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -1,6 +1,6 @@
 use crate::case::TextCase;
 
-#[derive(Debug, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Settings {
     pub max_line_length: usize,
     pub max_length: usize,
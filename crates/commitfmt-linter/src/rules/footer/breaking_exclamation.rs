@@ -1,12 +1,13 @@
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{FixMode, Violation, ViolationMetadata};
-use commitfmt_cc::{Footer, Message};
+use commitfmt_cc::{Edit, Message, Span, SyntaxKind, SyntaxTree};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
 /// Checks for the presence of a flag (exclamation mark)
-/// in a message containing `BREAKING CHANGES`.
+/// in a message containing a `BREAKING CHANGE` footer.
+/// `BREAKING-CHANGE` and the plural `BREAKING CHANGES` keys are recognized too.
 ///
 /// ## Why is this bad?
 /// Some utilities may not check commit footers and count on the presence of an exclamation mark.
@@ -16,17 +17,19 @@ use commitfmt_macros::ViolationMetadata;
 /// ```git-commit
 /// feat: my super feature
 ///
-/// BREAKING CHANGES: some breaking changes
+/// BREAKING CHANGE: some breaking changes
 /// ```
 ///
 /// Use instead:
 /// ```git-commit
 /// feat!: my super feature
 ///
-/// BREAKING CHANGES: some breaking changes
+/// BREAKING CHANGE: some breaking changes
 /// ```
 #[derive(ViolationMetadata)]
-pub(crate) struct BreakingExclamation;
+pub(crate) struct BreakingExclamation {
+    span: Option<Span>,
+}
 
 impl Violation for BreakingExclamation {
     fn group(&self) -> LinterGroup {
@@ -45,16 +48,25 @@ impl Violation for BreakingExclamation {
     fn message(&self) -> String {
         "Message contains breaking changes footer but no exclamation mark".to_string()
     }
+
+    fn edits(&self, tree: &SyntaxTree) -> Option<Vec<Edit>> {
+        let colon = tree.find(SyntaxKind::Colon).next()?;
+        Some(vec![Edit::insert(colon.range.start, "!")])
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
-/// Checks for exclamation mark in a message containing `BREAKING CHANGES`.
+/// Checks for exclamation mark in a message containing `BREAKING CHANGE`.
 pub(crate) fn breaking_exclamation(report: &mut Report, message: &Message) {
     if message.footers.is_empty() || message.header.breaking {
         return;
     }
 
-    if message.footers.iter().any(Footer::is_breaking_change) {
-        report.add_violation(Box::new(BreakingExclamation));
+    if let Some(footer) = message.footers.iter().find(|footer| footer.is_breaking_change()) {
+        report.add_violation(Box::new(BreakingExclamation { span: footer.key_span() }));
     }
 }
 
@@ -73,36 +85,56 @@ mod tests {
             value: "some breaking changes".to_string(),
             separator: ':',
             alignment: SeparatorAlignment::Left,
+            span: None,
         }];
 
         let message: Message = Message {
             header: Header::from("feat!: my feature"),
             body: None,
+            body_span: None,
             footers: footers.clone(),
         };
 
         breaking_exclamation(&mut report, &message);
         assert_eq!(report.len(), 0);
 
-        let message: Message =
-            Message { header: Header::from("feat: my feature"), body: None, footers };
+        let message: Message = Message {
+            header: Header::from("feat: my feature"),
+            body: None,
+            body_span: None,
+            footers,
+        };
 
         breaking_exclamation(&mut report, &message);
         assert_eq!(report.len(), 1);
         assert_eq!(report.violations[0].rule_name(), "BreakingExclamation");
     }
 
+    #[test]
+    fn test_spec_keys() {
+        for input in [
+            "feat: my feature\n\nBREAKING CHANGE: api",
+            "feat: my feature\n\nBREAKING-CHANGE: api",
+        ] {
+            let mut report = Report::default();
+            breaking_exclamation(&mut report, &Message::parse(input, None, None));
+            assert_eq!(report.len(), 1, "{input}");
+        }
+    }
+
     #[test]
     fn test_fix() {
         let mut message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![
                 {
                     key: "BREAKING CHANGES".to_string(),
                     value: "some breaking changes".to_string(),
                     separator: ':',
                     alignment: SeparatorAlignment::Left,
+                    span: None,
                 }
             ],
         };
@@ -116,4 +148,20 @@ mod tests {
         violation.fix(&mut message).unwrap();
         assert!(message.header.breaking);
     }
+
+    #[test]
+    fn test_edits() {
+        let input = "feat(api) : my feature\n\nBREAKING CHANGES :  some breaking changes";
+        let tree = SyntaxTree::parse(input, None, None);
+
+        let mut report = Report::default();
+        breaking_exclamation(&mut report, tree.message());
+        assert_eq!(report.len(), 1);
+
+        let edits = report.violations[0].edits(&tree).unwrap();
+        assert_eq!(
+            tree.apply(&edits),
+            "feat(api) !: my feature\n\nBREAKING CHANGES :  some breaking changes"
+        );
+    }
 }
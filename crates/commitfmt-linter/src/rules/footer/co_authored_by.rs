@@ -0,0 +1,340 @@
+use crate::report::Report;
+use crate::rules::LinterGroup;
+use crate::violation::{FixMode, Violation, ViolationError, ViolationMetadata};
+use commitfmt_cc::{Edit, Footer, Identity, Message, Span, SyntaxKind, SyntaxTree};
+use commitfmt_macros::ViolationMetadata;
+
+/// What is checked by the `co-authored-by` rule.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CoAuthorCheck {
+    /// Co-authors have valid identities and are not repeated.
+    #[default]
+    Identity,
+    /// The key is also spelled as `Co-authored-by`.
+    Canonical,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Problem {
+    InvalidIdentity(String),
+    Duplicate(String),
+    KeySpelling(String),
+}
+
+/// ## What it does
+/// Checks `Co-authored-by` footers: the value must be in the `Name <email>` form
+/// with a valid email, and each co-author must be listed once.
+/// With `co-authored-by = "canonical"`, the key must be spelled as `Co-authored-by`.
+///
+/// ## Why is this bad?
+/// Hosting services match co-authors by email, so a typo silently breaks the attribution.
+///
+/// ## Example
+/// ```git-commit
+/// feat: my feature
+///
+/// Co-Authored-By: John Doe <john@example>
+/// Co-Authored-By: Jane Doe <jane@example.com>
+/// Co-Authored-By: Jane Doe <jane@example.com>
+/// ```
+///
+/// Use instead:
+/// ```git-commit
+/// feat: my feature
+///
+/// Co-authored-by: John Doe <john@example.com>
+/// Co-authored-by: Jane Doe <jane@example.com>
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct CoAuthoredBy {
+    problem: Problem,
+    span: Option<Span>,
+}
+
+impl Violation for CoAuthoredBy {
+    fn group(&self) -> LinterGroup {
+        LinterGroup::Footer
+    }
+
+    fn message(&self) -> String {
+        match &self.problem {
+            Problem::InvalidIdentity(value) => {
+                format!(
+                    "Co-author '{value}' is not in the 'Name <email>' form with a valid email"
+                )
+            }
+            Problem::Duplicate(value) => {
+                format!("Co-author '{value}' is listed more than once")
+            }
+            Problem::KeySpelling(key) => {
+                format!("Co-author key '{key}' is not spelled as 'Co-authored-by'")
+            }
+        }
+    }
+
+    fn fix_mode(&self) -> FixMode {
+        match self.problem {
+            Problem::InvalidIdentity(_) => FixMode::Unfixable,
+            Problem::Duplicate(_) | Problem::KeySpelling(_) => FixMode::Safe,
+        }
+    }
+
+    fn fix(&self, message: &mut Message) -> Result<(), ViolationError> {
+        match self.problem {
+            Problem::InvalidIdentity(_) => return Err(ViolationError::Unfixable()),
+            Problem::Duplicate(_) => {
+                let mut seen: Vec<String> = Vec::new();
+                message.footers.retain(|footer| {
+                    let Some(identity) = co_author(footer) else {
+                        return true;
+                    };
+                    let email = identity.email.to_ascii_lowercase();
+                    if seen.contains(&email) {
+                        return false;
+                    }
+                    seen.push(email);
+                    true
+                });
+            }
+            Problem::KeySpelling(_) => {
+                for footer in
+                    message.footers.iter_mut().filter(|footer| is_co_author_key(&footer.key))
+                {
+                    footer.key = Footer::CO_AUTHORED_BY_KEY.to_string();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn edits(&self, tree: &SyntaxTree) -> Option<Vec<Edit>> {
+        let mut edits = Vec::new();
+        match self.problem {
+            Problem::InvalidIdentity(_) => return None,
+            Problem::Duplicate(_) => {
+                let mut seen: Vec<String> = Vec::new();
+                for (footer, node) in tree.footers() {
+                    let Some(identity) = co_author(footer) else {
+                        continue;
+                    };
+                    let email = identity.email.to_ascii_lowercase();
+                    if seen.contains(&email) {
+                        edits.push(tree.delete_line(node));
+                    } else {
+                        seen.push(email);
+                    }
+                }
+            }
+            Problem::KeySpelling(_) => {
+                for (_, node) in tree.footers().filter(|(footer, _)| {
+                    is_co_author_key(&footer.key) && footer.key != Footer::CO_AUTHORED_BY_KEY
+                }) {
+                    let key = node.child(SyntaxKind::FooterKey)?;
+                    edits.push(Edit::replace(key.range.clone(), Footer::CO_AUTHORED_BY_KEY));
+                }
+            }
+        }
+        Some(edits)
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+/// Checks `Co-authored-by` footers.
+///
+/// Each footer is checked for the identity, repetition and key spelling,
+/// so every problem is reported.
+pub(crate) fn co_authored_by(report: &mut Report, message: &Message, check: CoAuthorCheck) {
+    let mut identities: Vec<Identity> = Vec::new();
+
+    for footer in message.footers.iter().filter(|footer| is_co_author_key(&footer.key)) {
+        match footer.identity().filter(Identity::has_valid_email) {
+            Some(identity) if identities.iter().any(|other| other.same_email(&identity)) => {
+                report.add_violation(Box::new(CoAuthoredBy {
+                    problem: Problem::Duplicate(footer.value.clone()),
+                    span: footer.span,
+                }));
+            }
+            Some(identity) => identities.push(identity),
+            None => {
+                report.add_violation(Box::new(CoAuthoredBy {
+                    problem: Problem::InvalidIdentity(footer.value.clone()),
+                    span: footer.span,
+                }));
+            }
+        }
+
+        if check == CoAuthorCheck::Canonical && footer.key != Footer::CO_AUTHORED_BY_KEY {
+            report.add_violation(Box::new(CoAuthoredBy {
+                problem: Problem::KeySpelling(footer.key.clone()),
+                span: footer.key_span(),
+            }));
+        }
+    }
+}
+
+/// Returns the identity of the co-author footer.
+fn co_author(footer: &Footer) -> Option<Identity<'_>> {
+    if !is_co_author_key(&footer.key) {
+        return None;
+    }
+    footer.identity()
+}
+
+/// Checks that the key is a spelling of `Co-authored-by`,
+/// e.g. `Co-Authored-By`, `co-authored-by` or `Coauthored-by`.
+fn is_co_author_key(key: &str) -> bool {
+    let mut letters = key.chars().filter(|c| *c != '-').map(|c| c.to_ascii_lowercase());
+    "coauthoredby".chars().all(|expected| letters.next() == Some(expected))
+        && letters.next().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(input: &str, check: CoAuthorCheck) -> Report {
+        let mut report = Report::default();
+        co_authored_by(&mut report, &Message::parse(input, None, None), check);
+        report
+    }
+
+    #[test]
+    fn test_is_co_author_key() {
+        for key in ["Co-authored-by", "Co-Authored-By", "co-authored-by", "Coauthored-by"] {
+            assert!(is_co_author_key(key), "{key}");
+        }
+        for key in ["Authored-by", "Co-authored-byy", "Co-author"] {
+            assert!(!is_co_author_key(key), "{key}");
+        }
+    }
+
+    #[test]
+    fn test_co_authored_by() {
+        let input = "feat: my feature\n\n\
+            Co-authored-by: John Doe <john@example.com>\n\
+            Co-Authored-By: Jane Doe <jane@example.com>";
+        assert!(check(input, CoAuthorCheck::Identity).is_empty());
+        assert!(check("feat: my feature", CoAuthorCheck::Canonical).is_empty());
+
+        let report = check(input, CoAuthorCheck::Canonical);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.violations[0].rule_name(), "CoAuthoredBy");
+        assert_eq!(report.violations[0].fix_mode(), FixMode::Safe);
+    }
+
+    #[test]
+    fn test_invalid_identity() {
+        for value in ["John Doe", "John Doe <john@example>", "John Doe <john example.com>"] {
+            let input = format!("feat: my feature\n\nCo-authored-by: {value}");
+            let report = check(&input, CoAuthorCheck::Identity);
+            assert_eq!(report.len(), 1, "{value}");
+            assert_eq!(report.violations[0].fix_mode(), FixMode::Unfixable);
+        }
+    }
+
+    #[test]
+    fn test_key_spelling_and_invalid_identity() {
+        let input = "feat: my feature\n\nco-authored-by: Jane <jane>";
+        let report = check(input, CoAuthorCheck::Canonical);
+        let messages: Vec<String> =
+            report.violations.iter().map(|violation| violation.message()).collect();
+        assert_eq!(
+            messages,
+            [
+                "Co-author 'Jane <jane>' is not in the 'Name <email>' form with a valid email",
+                "Co-author key 'co-authored-by' is not spelled as 'Co-authored-by'",
+            ]
+        );
+        assert_eq!(report.violations[0].fix_mode(), FixMode::Unfixable);
+    }
+
+    #[test]
+    fn test_reports_every_footer() {
+        let input = "feat: my feature\n\n\
+            Co-authored-by: John Doe\n\
+            Co-authored-by: Jane Doe <jane@example.com>\n\
+            Co-authored-by: Jane <jane@example.com>\n\
+            Co-authored-by: Jim <jim@example>";
+        let report = check(input, CoAuthorCheck::Identity);
+        let messages: Vec<String> =
+            report.violations.iter().map(|violation| violation.message()).collect();
+        assert_eq!(
+            messages,
+            [
+                "Co-author 'John Doe' is not in the 'Name <email>' form with a valid email",
+                "Co-author 'Jane <jane@example.com>' is listed more than once",
+                "Co-author 'Jim <jim@example>' is not in the 'Name <email>' form with a valid email",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fix_duplicate() {
+        let input = "feat: my feature\n\n\
+            Co-authored-by: Jane Doe <jane@example.com>\n\
+            Issue: #1\n\
+            Co-authored-by: Jane <JANE@example.com>";
+        let mut message = Message::parse(input, None, None);
+        let mut report = Report::default();
+        co_authored_by(&mut report, &message, CoAuthorCheck::Identity);
+        assert_eq!(report.len(), 1);
+
+        report.violations[0].fix(&mut message).unwrap();
+        assert_eq!(
+            message.to_string(),
+            "feat: my feature\n\nCo-authored-by: Jane Doe <jane@example.com>\nIssue: #1"
+        );
+    }
+
+    #[test]
+    fn test_edits_duplicate() {
+        let input = "feat: my feature\n\n\
+            Co-authored-by: Jane Doe <jane@example.com>\n\
+            Issue:  #1\n\
+            Co-authored-by: Jane <JANE@example.com>\n\
+            # comment";
+        let tree = SyntaxTree::parse(input, None, None);
+        let mut report = Report::default();
+        co_authored_by(&mut report, tree.message(), CoAuthorCheck::Identity);
+        assert_eq!(report.len(), 1);
+
+        let edits = report.violations[0].edits(&tree).unwrap();
+        assert_eq!(
+            tree.apply(&edits),
+            "feat: my feature\n\nCo-authored-by: Jane Doe <jane@example.com>\nIssue:  #1\n# comment"
+        );
+    }
+
+    #[test]
+    fn test_fix_key_spelling() {
+        let input = "feat: my feature\n\nCo-Authored-By: Jane Doe <jane@example.com>";
+        let mut message = Message::parse(input, None, None);
+        let mut report = Report::default();
+        co_authored_by(&mut report, &message, CoAuthorCheck::Canonical);
+        assert_eq!(report.len(), 1);
+
+        report.violations[0].fix(&mut message).unwrap();
+        assert_eq!(
+            message.to_string(),
+            "feat: my feature\n\nCo-authored-by: Jane Doe <jane@example.com>"
+        );
+    }
+
+    #[test]
+    fn test_edits_key_spelling() {
+        let input = "feat: my feature\n\nCo-Authored-By :  Jane Doe <jane@example.com>";
+        let tree = SyntaxTree::parse(input, None, None);
+        let mut report = Report::default();
+        co_authored_by(&mut report, tree.message(), CoAuthorCheck::Canonical);
+        assert_eq!(report.len(), 1);
+
+        let edits = report.violations[0].edits(&tree).unwrap();
+        assert_eq!(
+            tree.apply(&edits),
+            "feat: my feature\n\nCo-authored-by :  Jane Doe <jane@example.com>"
+        );
+    }
+}
@@ -60,11 +60,13 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![{
                 key: "Authored-by".to_string(),
                 value: "John Doe".to_string(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             }],
         };
 
@@ -2,7 +2,7 @@ use crate::case::IdentifierCase;
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -32,6 +32,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct KeyCase {
     pub(crate) case: IdentifierCase,
+    span: Option<Span>,
 }
 
 impl Violation for KeyCase {
@@ -43,13 +44,17 @@ impl Violation for KeyCase {
         let case = self.case;
         format!("Footer key case is inconsistent. Expected: {case}")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for footer key case consistency
 pub(crate) fn key_case(report: &mut Report, message: &Message, case: IdentifierCase) {
     for footer in message.footers.iter() {
         if !case.is_match(&footer.key) {
-            report.add_violation(Box::new(KeyCase { case }));
+            report.add_violation(Box::new(KeyCase { case, span: footer.key_span() }));
             return;
         }
     }
@@ -68,24 +73,28 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![
                 {
                     key: "fixes".to_string(),
                     value: "#123".to_string(),
                     separator: ':',
                     alignment: SeparatorAlignment::Left,
+                    span: None,
                 },
                 {
                     key: "breaking-change".to_string(),
                     value: "removed API".to_string(),
                     separator: ':',
                     alignment: SeparatorAlignment::Left,
+                    span: None,
                 },
                 {
                     key: "signed-off-by".to_string(),
                     value: "John Doe".to_string(),
                     separator: ':',
                     alignment: SeparatorAlignment::Left,
+                    span: None,
                 }
             ],
         };
@@ -96,24 +105,28 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![
                 {
                     key: "Fixes".to_string(),
                     value: "#123".to_string(),
                     separator: ':',
                     alignment: SeparatorAlignment::Left,
+                    span: None,
                 },
                 {
                     key: "BreakingChange".to_string(),
                     value: "removed API".to_string(),
                     separator: ':',
                     alignment: SeparatorAlignment::Left,
+                    span: None,
                 },
                 {
                     key: "signed_off_by".to_string(),
                     value: "John Doe".to_string(),
                     separator: ':',
                     alignment: SeparatorAlignment::Left,
+                    span: None,
                 }
             ],
         };
@@ -122,4 +135,15 @@ mod tests {
         assert_eq!(report.len(), 1);
         assert_eq!(report.violations[0].rule_name(), "KeyCase");
     }
+
+    #[test]
+    fn test_key_case_span() {
+        let mut report = Report::default();
+        let message =
+            Message::parse("feat: my feature\n\nfixes: #123\nSignedOff: John", None, None);
+
+        key_case(&mut report, &message, IdentifierCase::Kebab);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.violations[0].span().unwrap().to_string(), "4:1-4:10");
+    }
 }
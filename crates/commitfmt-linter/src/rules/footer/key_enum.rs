@@ -0,0 +1,192 @@
+use crate::report::Report;
+use crate::rules::LinterGroup;
+use crate::violation::{FixMode, Violation, ViolationError, ViolationMetadata};
+use commitfmt_cc::{Edit, Footer, Message, Span, SyntaxKind, SyntaxTree};
+use commitfmt_macros::ViolationMetadata;
+
+/// Git trailers that are allowed in addition to the configured keys.
+/// Breaking change keys are allowed as well.
+const DEFAULT_KEYS: &[&str] = &[
+    Footer::SIGNED_OFF_BY_KEY,
+    Footer::CO_AUTHORED_BY_KEY,
+    "Acked-by",
+    "Reviewed-by",
+    "Tested-by",
+    "Reported-by",
+    "Suggested-by",
+    "Helped-by",
+    "Cc",
+];
+
+/// ## What it does
+/// Checks that footer keys are in the allowed list.
+/// `BREAKING CHANGE` and standard git trailers like `Signed-off-by` are always allowed.
+///
+/// Keys that differ only in case and configured aliases are renamed by the fix.
+///
+/// ## Why is this bad?
+/// Automated tools look for the exact key. Variants like `Ticket`, `Task-Id` and `Jira`
+/// for the same footer are not picked up.
+///
+/// ## Example
+/// ```git-commit
+/// feat: my feature
+///
+/// Jira: PRJ-123
+/// ```
+///
+/// Use instead:
+/// ```git-commit
+/// feat: my feature
+///
+/// Issue-ID: PRJ-123
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct KeyEnum {
+    key: String,
+    replacement: Option<String>,
+    span: Option<Span>,
+}
+
+impl Violation for KeyEnum {
+    fn group(&self) -> LinterGroup {
+        LinterGroup::Footer
+    }
+
+    fn message(&self) -> String {
+        let key = &self.key;
+        format!("Footer key '{key}' is not allowed")
+    }
+
+    fn fix_mode(&self) -> FixMode {
+        if self.replacement.is_some() {
+            FixMode::Safe
+        } else {
+            FixMode::Unfixable
+        }
+    }
+
+    fn fix(&self, message: &mut Message) -> Result<(), ViolationError> {
+        let Some(replacement) = &self.replacement else {
+            return Err(ViolationError::Unfixable());
+        };
+        for footer in message.footers.iter_mut().filter(|footer| footer.key == self.key) {
+            footer.key.clone_from(replacement);
+        }
+        Ok(())
+    }
+
+    fn edits(&self, tree: &SyntaxTree) -> Option<Vec<Edit>> {
+        let replacement = self.replacement.as_ref()?;
+        let edits = tree
+            .footers()
+            .filter(|(footer, _)| footer.key == self.key)
+            .filter_map(|(_, node)| node.child(SyntaxKind::FooterKey))
+            .map(|key| Edit::replace(key.range.clone(), replacement.as_str()))
+            .collect();
+        Some(edits)
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+/// Checks that footer keys are allowed.
+/// `aliases` maps known variants to the allowed keys.
+pub(crate) fn key_enum(
+    report: &mut Report,
+    message: &Message,
+    allowed: &[Box<str>],
+    aliases: &[(Box<str>, Box<str>)],
+) {
+    let allowed_keys = || allowed.iter().map(|key| &**key).chain(DEFAULT_KEYS.iter().copied());
+
+    for footer in message.footers.iter() {
+        if footer.is_breaking_change() || allowed_keys().any(|key| key == footer.key) {
+            continue;
+        }
+
+        let replacement = aliases
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(&footer.key))
+            .map(|(_, key)| &**key)
+            .or_else(|| allowed_keys().find(|key| key.eq_ignore_ascii_case(&footer.key)));
+
+        report.add_violation(Box::new(KeyEnum {
+            key: footer.key.clone(),
+            replacement: replacement.map(str::to_string),
+            span: footer.key_span(),
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(input: &str) -> Report {
+        let allowed = vec!["Issue-ID".into()];
+        let aliases = vec![("Jira".into(), "Issue-ID".into())];
+
+        let mut report = Report::default();
+        key_enum(&mut report, &Message::parse(input, None, None), &allowed, &aliases);
+        report
+    }
+
+    #[test]
+    fn test_key_enum() {
+        let input = "feat: my feature\n\n\
+            Issue-ID: PRJ-1\n\
+            BREAKING CHANGE: api\n\
+            Signed-off-by: John Doe <john@example.com>";
+        assert!(check(input).is_empty());
+
+        let report = check("feat: my feature\n\nTicket: PRJ-1");
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.violations[0].rule_name(), "KeyEnum");
+        assert_eq!(report.violations[0].message(), "Footer key 'Ticket' is not allowed");
+        assert_eq!(report.violations[0].fix_mode(), FixMode::Unfixable);
+    }
+
+    #[test]
+    fn test_fix() {
+        let input =
+            "feat: my feature\n\njira: PRJ-1\nsigned-off-by: John Doe <john@example.com>";
+        let mut message = Message::parse(input, None, None);
+        let mut report = Report::default();
+        key_enum(
+            &mut report,
+            &message,
+            &["Issue-ID".into()],
+            &[("Jira".into(), "Issue-ID".into())],
+        );
+        assert_eq!(report.len(), 2);
+
+        for violation in &report.violations {
+            assert_eq!(violation.fix_mode(), FixMode::Safe);
+            violation.fix(&mut message).unwrap();
+        }
+        assert_eq!(
+            message.to_string(),
+            "feat: my feature\n\nIssue-ID: PRJ-1\nSigned-off-by: John Doe <john@example.com>"
+        );
+    }
+
+    #[test]
+    fn test_edits() {
+        let input = "feat: my feature\n\njira:  PRJ-1\n# comment";
+        let tree = SyntaxTree::parse(input, None, None);
+        let mut report = Report::default();
+        key_enum(
+            &mut report,
+            tree.message(),
+            &["Issue-ID".into()],
+            &[("Jira".into(), "Issue-ID".into())],
+        );
+        assert_eq!(report.len(), 1);
+
+        let edits = report.violations[0].edits(&tree).unwrap();
+        assert_eq!(tree.apply(&edits), "feat: my feature\n\nIssue-ID:  PRJ-1\n# comment");
+    }
+}
@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -34,6 +34,7 @@ use commitfmt_macros::ViolationMetadata;
 pub(crate) struct MaxLength {
     key: String,
     length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for MaxLength {
@@ -46,6 +47,10 @@ impl Violation for MaxLength {
         let length = self.length;
         format!("Footer '{key}' length is longer than {length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for long footers
@@ -56,7 +61,8 @@ pub(crate) fn max_length(report: &mut Report, message: &Message, length: usize)
 
     for footer in message.footers.iter() {
         if footer.len() > length {
-            let violation = Box::new(MaxLength { key: footer.key.clone(), length });
+            let violation =
+                Box::new(MaxLength { key: footer.key.clone(), length, span: footer.span });
             report.add_violation(violation);
             break;
         }
@@ -76,11 +82,13 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![{
                 key: "BREAKING CHANGES".to_string(),
                 value: "some breaking changes".to_string(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             }],
         };
 
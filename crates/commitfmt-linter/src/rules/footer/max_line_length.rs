@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::{longer_than_chars, LinterGroup};
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -29,6 +29,7 @@ use commitfmt_macros::ViolationMetadata;
 pub(crate) struct MaxLineLength {
     key: String,
     length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for MaxLineLength {
@@ -43,6 +44,10 @@ impl Violation for MaxLineLength {
             "Footer '{key}' contains a line that length is longer than {length} characters"
         )
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for long footers
@@ -54,7 +59,11 @@ pub(crate) fn max_line_length(report: &mut Report, message: &Message, length: us
     for footer in message.footers.iter() {
         for line in footer.value.lines() {
             if longer_than_chars(line, length) {
-                let violation = Box::new(MaxLineLength { key: footer.key.clone(), length });
+                let violation = Box::new(MaxLineLength {
+                    key: footer.key.clone(),
+                    length,
+                    span: footer.span,
+                });
                 report.add_violation(violation);
                 break;
             }
@@ -75,11 +84,13 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![{
                 key: "BREAKING CHANGES".to_string(),
                 value: "some breaking changes".to_string(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             }],
         };
 
@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -27,6 +27,7 @@ use commitfmt_macros::ViolationMetadata;
 pub(crate) struct MinLength {
     key: String,
     length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for MinLength {
@@ -39,6 +40,10 @@ impl Violation for MinLength {
         let length = self.length;
         format!("Footer '{key}' length is less than {length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for short footers
@@ -49,7 +54,8 @@ pub(crate) fn min_length(report: &mut Report, message: &Message, length: usize)
 
     for footer in message.footers.iter() {
         if footer.len() < length {
-            let violation = Box::new(MinLength { key: footer.key.clone(), length });
+            let violation =
+                Box::new(MinLength { key: footer.key.clone(), length, span: footer.span });
             report.add_violation(violation);
             break;
         }
@@ -69,11 +75,13 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![{
                 key: "BREAKING CHANGES".to_string(),
                 value: "some breaking changes".to_string(),
                 separator: ':',
                 alignment: SeparatorAlignment::Left,
+                span: None,
             }],
         };
 
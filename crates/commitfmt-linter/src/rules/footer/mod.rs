@@ -1,9 +1,13 @@
 mod breaking_exclamation;
+mod co_authored_by;
 mod exists;
 mod key_case;
+mod key_enum;
 mod max_length;
 mod max_line_length;
 mod min_length;
+mod signed_off_by;
+mod value_pattern;
 
 mod settings;
 
@@ -11,10 +15,18 @@ mod settings;
 #[allow(unused)]
 pub(crate) use {
     breaking_exclamation::{breaking_exclamation, BreakingExclamation},
+    co_authored_by::{co_authored_by, CoAuthoredBy},
     exists::{exists, Exists},
     key_case::{key_case, KeyCase},
+    key_enum::{key_enum, KeyEnum},
     max_length::{max_length, MaxLength},
     max_line_length::{max_line_length, MaxLineLength},
     min_length::{min_length, MinLength},
     settings::Settings,
+    signed_off_by::{signed_off_by, SignedOffBy},
+    value_pattern::{value_pattern, ValuePattern},
+};
+
+pub use {
+    co_authored_by::CoAuthorCheck, signed_off_by::SignOffCheck, value_pattern::KeyPattern,
 };
@@ -1,7 +1,9 @@
 // use crate::case::{TextCase, WordCase};
 use crate::case::IdentifierCase;
 
-#[derive(Debug, PartialEq, Default)]
+use super::{CoAuthorCheck, KeyPattern, SignOffCheck};
+
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Settings {
     pub max_line_length: usize,
     pub max_length: usize,
@@ -10,4 +12,10 @@ pub struct Settings {
     // pub title_case: WordCase,
     // pub value_case: TextCase,
     pub required: Vec<Box<str>>,
+    pub sign_off: SignOffCheck,
+    pub co_author: CoAuthorCheck,
+    pub value_pattern: Vec<KeyPattern>,
+    pub key_enum: Vec<Box<str>>,
+    /// Known key variants and the allowed keys they are renamed to
+    pub key_aliases: Vec<(Box<str>, Box<str>)>,
 }
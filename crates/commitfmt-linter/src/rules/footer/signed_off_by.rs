@@ -0,0 +1,274 @@
+use crate::report::Report;
+use crate::rules::LinterGroup;
+use crate::violation::{FixMode, Violation, ViolationError, ViolationMetadata};
+use commitfmt_cc::{Edit, Footer, Identity, Message, SeparatorAlignment, Span, SyntaxTree};
+use commitfmt_macros::ViolationMetadata;
+
+/// What is checked by the `signed-off-by` rule.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SignOffCheck {
+    /// The trailer exists and has the `Name <email>` form.
+    #[default]
+    Format,
+    /// The trailer also matches the commit author.
+    Author,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Problem {
+    Missing,
+    InvalidFormat(String),
+    AuthorMismatch,
+}
+
+/// ## What it does
+/// Checks that the message is signed off with a `Signed-off-by: Name <email>` trailer
+/// as required by the Developer Certificate of Origin.
+/// With `signed-off-by = "author"`, the email of one of the trailers must match the commit author.
+///
+/// The fix appends the trailer with the author identity
+/// (`user.name` and `user.email` when committing).
+///
+/// ## Why is this bad?
+/// Projects that use DCO reject contributions without sign-off,
+/// and the check is usually done only after the changes are pushed.
+///
+/// ## Example
+/// ```git-commit
+/// feat: my feature
+/// ```
+///
+/// Use instead:
+/// ```git-commit
+/// feat: my feature
+///
+/// Signed-off-by: John Doe <john@example.com>
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct SignedOffBy {
+    problem: Problem,
+    author: Option<String>,
+    span: Option<Span>,
+}
+
+impl Violation for SignedOffBy {
+    fn group(&self) -> LinterGroup {
+        LinterGroup::Footer
+    }
+
+    fn message(&self) -> String {
+        match &self.problem {
+            Problem::Missing => {
+                "Message is not signed off with 'Signed-off-by' footer".to_string()
+            }
+            Problem::InvalidFormat(value) => {
+                format!("Sign-off '{value}' is not in the 'Name <email>' form")
+            }
+            Problem::AuthorMismatch => {
+                let author = self.author.as_deref().unwrap_or_default();
+                format!("Message is not signed off by the author '{author}'")
+            }
+        }
+    }
+
+    fn fix_mode(&self) -> FixMode {
+        match (&self.problem, &self.author) {
+            (Problem::Missing, Some(_)) => FixMode::Safe,
+            (Problem::AuthorMismatch, Some(_)) => FixMode::Unsafe,
+            _ => FixMode::Unfixable,
+        }
+    }
+
+    fn fix(&self, message: &mut Message) -> Result<(), ViolationError> {
+        if self.fix_mode() == FixMode::Unfixable {
+            return Err(ViolationError::Unfixable());
+        }
+        let Some(footer) = self.sign_off() else {
+            return Err(ViolationError::Empty("author".to_string()));
+        };
+
+        message.footers.push(footer);
+        Ok(())
+    }
+
+    fn edits(&self, tree: &SyntaxTree) -> Option<Vec<Edit>> {
+        if self.fix_mode() == FixMode::Unfixable {
+            return None;
+        }
+        let edit = tree.append_footer(&self.sign_off()?)?;
+        Some(vec![edit])
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+impl SignedOffBy {
+    /// Returns the sign-off footer of the author
+    fn sign_off(&self) -> Option<Footer> {
+        Some(Footer {
+            key: Footer::SIGNED_OFF_BY_KEY.to_string(),
+            value: self.author.clone()?,
+            separator: Footer::DEFAULT_SEPARATOR_CHAR,
+            alignment: SeparatorAlignment::default(),
+            span: None,
+        })
+    }
+}
+
+/// Checks that the message is signed off.
+/// `author` is the commit author in the `Name <email>` form, if known.
+pub(crate) fn signed_off_by(
+    report: &mut Report,
+    message: &Message,
+    check: SignOffCheck,
+    author: Option<&str>,
+) {
+    let mut identities = Vec::new();
+    let mut is_signed = false;
+    let signatures =
+        message.footers.iter().filter(|footer| footer.key == Footer::SIGNED_OFF_BY_KEY);
+
+    for footer in signatures {
+        is_signed = true;
+        let Some(identity) = footer.identity().filter(Identity::has_valid_email) else {
+            let author = author.map(str::to_string);
+            report.add_violation(Box::new(SignedOffBy {
+                problem: Problem::InvalidFormat(footer.value.clone()),
+                author,
+                span: footer.span,
+            }));
+            continue;
+        };
+        identities.push(identity);
+    }
+
+    if !is_signed {
+        report.add_violation(Box::new(SignedOffBy {
+            problem: Problem::Missing,
+            author: author.map(str::to_string),
+            span: None,
+        }));
+        return;
+    }
+
+    if check != SignOffCheck::Author {
+        return;
+    }
+    let Some(author_identity) = author.and_then(Identity::parse) else {
+        return;
+    };
+    if !identities.iter().any(|identity| identity.same_email(&author_identity)) {
+        report.add_violation(Box::new(SignedOffBy {
+            problem: Problem::AuthorMismatch,
+            author: author.map(str::to_string),
+            span: None,
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AUTHOR: &str = "John Doe <john@example.com>";
+
+    fn check(input: &str, check: SignOffCheck, author: Option<&str>) -> Report {
+        let mut report = Report::default();
+        signed_off_by(&mut report, &Message::parse(input, None, None), check, author);
+        report
+    }
+
+    #[test]
+    fn test_signed_off_by() {
+        let signed = "feat: my feature\n\nSigned-off-by: John Doe <john@example.com>";
+        assert!(check(signed, SignOffCheck::Format, None).is_empty());
+        assert!(check(signed, SignOffCheck::Author, Some(AUTHOR)).is_empty());
+        assert!(
+            check(signed, SignOffCheck::Author, Some("John <JOHN@example.com>")).is_empty()
+        );
+
+        let report = check("feat: my feature", SignOffCheck::Format, None);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.violations[0].rule_name(), "SignedOffBy");
+        assert_eq!(report.violations[0].fix_mode(), FixMode::Unfixable);
+    }
+
+    #[test]
+    fn test_invalid_format() {
+        for value in
+            ["John Doe", "<john@example.com>", "John <john>", "John <john@example.com"]
+        {
+            let input = format!("feat: my feature\n\nSigned-off-by: {value}");
+            let report = check(&input, SignOffCheck::Format, Some(AUTHOR));
+            assert_eq!(report.len(), 1, "{value}");
+            assert_eq!(report.violations[0].fix_mode(), FixMode::Unfixable);
+        }
+    }
+
+    #[test]
+    fn test_reports_every_invalid_format() {
+        let input = "feat: my feature\n\n\
+            Signed-off-by: John\n\
+            Signed-off-by: Jane <jane>\n\
+            Signed-off-by: Jane Doe <jane@example.com>";
+        let report = check(input, SignOffCheck::Author, Some(AUTHOR));
+        let problems: Vec<String> =
+            report.violations.iter().map(|violation| violation.message()).collect();
+        assert_eq!(
+            problems,
+            [
+                "Sign-off 'John' is not in the 'Name <email>' form",
+                "Sign-off 'Jane <jane>' is not in the 'Name <email>' form",
+                "Message is not signed off by the author 'John Doe <john@example.com>'",
+            ]
+        );
+        assert_eq!(report.violations[2].fix_mode(), FixMode::Unsafe);
+
+        let input = format!("{input}\nSigned-off-by: {AUTHOR}");
+        assert_eq!(check(&input, SignOffCheck::Author, Some(AUTHOR)).len(), 2);
+    }
+
+    #[test]
+    fn test_author_mismatch() {
+        let input = "feat: my feature\n\nSigned-off-by: Jane Doe <jane@example.com>";
+        assert!(check(input, SignOffCheck::Format, Some(AUTHOR)).is_empty());
+
+        let report = check(input, SignOffCheck::Author, Some(AUTHOR));
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.violations[0].fix_mode(), FixMode::Unsafe);
+
+        let input = format!("{input}\nSigned-off-by: {AUTHOR}");
+        assert!(check(&input, SignOffCheck::Author, Some(AUTHOR)).is_empty());
+    }
+
+    #[test]
+    fn test_fix() {
+        let mut message = Message::parse("feat: my feature", None, None);
+        let mut report = Report::default();
+        signed_off_by(&mut report, &message, SignOffCheck::Format, Some(AUTHOR));
+
+        let violation = report.violations[0].as_ref();
+        assert_eq!(violation.fix_mode(), FixMode::Safe);
+        violation.fix(&mut message).unwrap();
+        assert_eq!(
+            message.to_string(),
+            "feat: my feature\n\nSigned-off-by: John Doe <john@example.com>"
+        );
+    }
+
+    #[test]
+    fn test_edits() {
+        let input = "feat:  my feature\n\nRefs: #1\n\n# comment";
+        let tree = SyntaxTree::parse(input, None, None);
+        let mut report = Report::default();
+        signed_off_by(&mut report, tree.message(), SignOffCheck::Format, Some(AUTHOR));
+
+        let edits = report.violations[0].edits(&tree).unwrap();
+        assert_eq!(
+            tree.apply(&edits),
+            "feat:  my feature\n\nRefs: #1\nSigned-off-by: John Doe <john@example.com>\n\n# comment"
+        );
+    }
+}
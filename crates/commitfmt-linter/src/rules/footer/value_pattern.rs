@@ -0,0 +1,116 @@
+use crate::report::Report;
+use crate::rules::LinterGroup;
+use crate::violation::{Violation, ViolationMetadata};
+use commitfmt_cc::{Message, Span};
+use commitfmt_macros::ViolationMetadata;
+use regex_lite::Regex;
+
+/// Pattern that values of the footer with the key must match.
+#[derive(Debug, Clone)]
+pub struct KeyPattern {
+    pub key: Box<str>,
+    pub pattern: Regex,
+}
+
+impl PartialEq for KeyPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.pattern.as_str() == other.pattern.as_str()
+    }
+}
+
+/// ## What it does
+/// Checks that footer values match the pattern configured for their key.
+///
+/// ## Why is this bad?
+/// Automated tools parse footer values, e.g. to link issues.
+/// A value in the unexpected format is silently ignored by them.
+///
+/// ## Example
+/// ```git-commit
+/// feat: my feature
+///
+/// Issue-ID: 123
+/// ```
+///
+/// Use instead:
+/// ```git-commit
+/// feat: my feature
+///
+/// Issue-ID: PRJ-123
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct ValuePattern {
+    key: String,
+    value: String,
+    pattern: String,
+    span: Option<Span>,
+}
+
+impl Violation for ValuePattern {
+    fn group(&self) -> LinterGroup {
+        LinterGroup::Footer
+    }
+
+    fn message(&self) -> String {
+        let key = &self.key;
+        let value = &self.value;
+        let pattern = &self.pattern;
+        format!("Footer '{key}' value '{value}' does not match pattern '{pattern}'")
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+/// Checks that footer values match the patterns of their keys
+pub(crate) fn value_pattern(report: &mut Report, message: &Message, patterns: &[KeyPattern]) {
+    for footer in message.footers.iter() {
+        let Some(rule) = patterns.iter().find(|rule| *rule.key == footer.key) else {
+            continue;
+        };
+        if !rule.pattern.is_match(&footer.value) {
+            report.add_violation(Box::new(ValuePattern {
+                key: footer.key.clone(),
+                value: footer.value.clone(),
+                pattern: rule.pattern.as_str().to_string(),
+                span: footer.span,
+            }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_pattern() {
+        let patterns = vec![
+            KeyPattern {
+                key: "Issue-ID".into(),
+                pattern: Regex::new("^[A-Z]+-[0-9]+$").unwrap(),
+            },
+            KeyPattern { key: "Refs".into(), pattern: Regex::new(r"^#\d+$").unwrap() },
+        ];
+        let message = Message::parse(
+            "feat: my feature\n\nIssue-ID: PRJ-123\nRefs: 12\nAuthored-by: John",
+            None,
+            None,
+        );
+
+        let mut report = Report::default();
+        value_pattern(&mut report, &message, &patterns);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.violations[0].rule_name(), "ValuePattern");
+        assert_eq!(
+            report.violations[0].message(),
+            r"Footer 'Refs' value '12' does not match pattern '^#\d+$'"
+        );
+        assert!(report.violations[0].span().is_some());
+
+        let mut report = Report::default();
+        value_pattern(&mut report, &message, &patterns[..1]);
+        assert!(report.is_empty());
+    }
+}
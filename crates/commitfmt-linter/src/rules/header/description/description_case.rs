@@ -2,7 +2,7 @@ use crate::case::TextCase;
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -24,6 +24,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct DescriptionCase {
     pub(crate) case: TextCase,
+    span: Option<Span>,
 }
 
 impl Violation for DescriptionCase {
@@ -35,12 +36,19 @@ impl Violation for DescriptionCase {
         let case = self.case;
         format!("Description case is inconsistent. Expected: {case}")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for scope case consistency
 pub(crate) fn description_case(report: &mut Report, message: &Message, case: TextCase) {
     if !case.is_match(&message.header.description) {
-        report.add_violation(Box::new(DescriptionCase { case }));
+        report.add_violation(Box::new(DescriptionCase {
+            case,
+            span: message.header.description_span,
+        }));
     }
 }
 
@@ -57,6 +65,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat(db-core, ui-core, req-internal): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -66,6 +75,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: My feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -1,4 +1,4 @@
-use commitfmt_cc::Message;
+use commitfmt_cc::{Edit, Message, Span, SyntaxKind, SyntaxTree};
 use commitfmt_macros::ViolationMetadata;
 
 use crate::report::Report;
@@ -22,7 +22,9 @@ use crate::violation::{FixMode, Violation, ViolationMetadata};
 /// feat: my feature
 /// ```
 #[derive(ViolationMetadata)]
-pub(crate) struct DescriptionFullStop;
+pub(crate) struct DescriptionFullStop {
+    span: Option<Span>,
+}
 
 impl Violation for DescriptionFullStop {
     fn group(&self) -> LinterGroup {
@@ -43,12 +45,25 @@ impl Violation for DescriptionFullStop {
     fn message(&self) -> String {
         format!("Header description is ended with a full stop")
     }
+
+    fn edits(&self, tree: &SyntaxTree) -> Option<Vec<Edit>> {
+        let description = tree.find(SyntaxKind::Description).next()?;
+        let trimmed = tree.text(description).trim_end_matches('.');
+        let start = description.range.start + trimmed.len();
+
+        Some(vec![Edit::delete(start..description.range.end)])
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for body ending with full stop
 pub(crate) fn description_full_stop(report: &mut Report, message: &Message) {
     if message.header.description.ends_with('.') {
-        let violation = Box::new(DescriptionFullStop);
+        let violation =
+            Box::new(DescriptionFullStop { span: message.header.description_span });
         report.add_violation(violation);
     }
 }
@@ -66,6 +81,7 @@ mod tests {
         let mut message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -83,6 +99,7 @@ mod tests {
         let mut message = Message {
             header: Header::from("feat: my feature."),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -97,4 +114,17 @@ mod tests {
 
         assert_eq!(message.header.description, "my feature");
     }
+
+    #[test]
+    fn test_edits() {
+        let input = "feat:  my feature..\n\n# keep  this\nbody";
+        let tree = SyntaxTree::parse(input, None, None);
+
+        let mut report = Report::default();
+        description_full_stop(&mut report, tree.message());
+        assert_eq!(report.len(), 1);
+
+        let edits = report.violations[0].edits(&tree).unwrap();
+        assert_eq!(tree.apply(&edits), "feat:  my feature\n\n# keep  this\nbody");
+    }
 }
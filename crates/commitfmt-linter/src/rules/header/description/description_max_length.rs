@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::{longer_than_chars, LinterGroup};
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -22,6 +22,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct DescriptionMaxLength {
     pub(crate) length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for DescriptionMaxLength {
@@ -33,12 +34,19 @@ impl Violation for DescriptionMaxLength {
         let length = self.length;
         format!("Description is longer than {length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for scope maximum length
 pub(crate) fn description_max_length(report: &mut Report, message: &Message, length: usize) {
     if longer_than_chars(&message.header.description, length) {
-        report.add_violation(Box::new(DescriptionMaxLength { length }));
+        report.add_violation(Box::new(DescriptionMaxLength {
+            length,
+            span: message.header.description_span,
+        }));
     }
 }
 
@@ -55,6 +63,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat(db, ui): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         description_max_length(&mut report, &message, 72);
@@ -65,6 +74,7 @@ mod tests {
                 "feat: my feature description where i added some bugs and fixed some others which are longer than 72 characters",
             ),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         description_max_length(&mut report, &message, 72);
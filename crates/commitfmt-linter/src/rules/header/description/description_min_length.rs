@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::{shorter_than_chars, LinterGroup};
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -22,6 +22,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct DescriptionMinLength {
     pub(crate) length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for DescriptionMinLength {
@@ -33,12 +34,19 @@ impl Violation for DescriptionMinLength {
         let length = self.length;
         format!("Description is shorter than {length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for scope maximum length
 pub(crate) fn description_min_length(report: &mut Report, message: &Message, length: usize) {
     if shorter_than_chars(&message.header.description, length) {
-        report.add_violation(Box::new(DescriptionMinLength { length }));
+        report.add_violation(Box::new(DescriptionMinLength {
+            length,
+            span: message.header.description_span,
+        }));
     }
 }
 
@@ -55,13 +63,18 @@ mod tests {
         let message: Message = Message {
             header: Header::from("test: add more cases for parser"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         description_min_length(&mut report, &message, 5);
         assert_eq!(report.len(), 0);
 
-        let message: Message =
-            Message { header: Header::from("test: add"), body: None, footers: footer_vec![] };
+        let message: Message = Message {
+            header: Header::from("test: add"),
+            body: None,
+            body_span: None,
+            footers: footer_vec![],
+        };
         description_min_length(&mut report, &message, 5);
         assert_eq!(report.len(), 1);
         assert_eq!(report.violations[0].rule_name(), "DescriptionMinLength");
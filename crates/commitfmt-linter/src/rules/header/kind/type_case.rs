@@ -2,7 +2,7 @@ use crate::case::IdentifierCase;
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -24,6 +24,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct TypeCase {
     pub(crate) case: IdentifierCase,
+    span: Option<Span>,
 }
 
 impl Violation for TypeCase {
@@ -35,6 +36,10 @@ impl Violation for TypeCase {
         let case = self.case;
         format!("Type case is inconsistent. Expected: {case}")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for scope case consistency
@@ -44,7 +49,7 @@ pub(crate) fn type_case(report: &mut Report, message: &Message, case: Identifier
     };
 
     if !case.is_match(kind) {
-        report.add_violation(Box::new(TypeCase { case }));
+        report.add_violation(Box::new(TypeCase { case, span: message.header.kind_span }));
     }
 }
 
@@ -61,6 +66,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -70,6 +76,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("Feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
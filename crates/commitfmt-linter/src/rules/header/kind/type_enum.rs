@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -23,6 +23,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct TypeEnum {
     miss: String,
+    span: Option<Span>,
 }
 
 impl Violation for TypeEnum {
@@ -35,6 +36,10 @@ impl Violation for TypeEnum {
         let miss = &self.miss;
         format!("Type is not allowed: {miss}")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for scope case consistency
@@ -49,7 +54,10 @@ pub(crate) fn type_enum(report: &mut Report, message: &Message, allowed: &Vec<Bo
         }
     }
 
-    report.add_violation(Box::new(TypeEnum { miss: kind.clone() }));
+    report.add_violation(Box::new(TypeEnum {
+        miss: kind.clone(),
+        span: message.header.kind_span,
+    }));
 }
 
 #[cfg(test)]
@@ -67,6 +75,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -76,6 +85,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feature: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::{longer_than_chars, LinterGroup};
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -23,6 +23,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct TypeMaxLength {
     pub(crate) length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for TypeMaxLength {
@@ -34,6 +35,10 @@ impl Violation for TypeMaxLength {
         let length = self.length;
         format!("Type is longer than {length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for scope maximum length
@@ -43,7 +48,8 @@ pub(crate) fn type_max_length(report: &mut Report, message: &Message, length: us
     };
 
     if longer_than_chars(kind, length) {
-        report.add_violation(Box::new(TypeMaxLength { length }));
+        report
+            .add_violation(Box::new(TypeMaxLength { length, span: message.header.kind_span }));
     }
 }
 
@@ -60,6 +66,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("i18n:  add greek support"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         type_max_length(&mut report, &message, 10);
@@ -68,6 +75,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("internationalization: add greek support"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         type_max_length(&mut report, &message, 10);
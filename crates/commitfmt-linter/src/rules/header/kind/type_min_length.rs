@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::{shorter_than_chars, LinterGroup};
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -22,6 +22,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct TypeMinLength {
     pub(crate) length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for TypeMinLength {
@@ -33,17 +34,23 @@ impl Violation for TypeMinLength {
         let length = self.length;
         format!("Type is shorter than {length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for scope maximum length
 pub(crate) fn type_min_length(report: &mut Report, message: &Message, length: usize) {
     let Some(kind) = &message.header.kind else {
-        report.add_violation(Box::new(TypeMinLength { length }));
+        report
+            .add_violation(Box::new(TypeMinLength { length, span: message.header.kind_span }));
         return;
     };
 
     if shorter_than_chars(kind, length) {
-        report.add_violation(Box::new(TypeMinLength { length }));
+        report
+            .add_violation(Box::new(TypeMinLength { length, span: message.header.kind_span }));
     }
 }
 
@@ -60,13 +67,18 @@ mod tests {
         let message: Message = Message {
             header: Header::from("test: add more cases for parser"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         type_min_length(&mut report, &message, 1);
         assert_eq!(report.len(), 0);
 
-        let message: Message =
-            Message { header: Header::from("tests"), body: None, footers: footer_vec![] };
+        let message: Message = Message {
+            header: Header::from("tests"),
+            body: None,
+            body_span: None,
+            footers: footer_vec![],
+        };
         type_min_length(&mut report, &message, 1);
         assert_eq!(report.len(), 1);
         assert_eq!(report.violations[0].rule_name(), "TypeMinLength");
@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -21,7 +21,9 @@ use commitfmt_macros::ViolationMetadata;
 /// feat: my feature
 /// ```
 #[derive(ViolationMetadata)]
-pub(crate) struct TypeRequired;
+pub(crate) struct TypeRequired {
+    span: Option<Span>,
+}
 
 impl Violation for TypeRequired {
     fn group(&self) -> LinterGroup {
@@ -32,12 +34,16 @@ impl Violation for TypeRequired {
     fn message(&self) -> String {
         format!("Commit type is required")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for scope case consistency
 pub(crate) fn type_required(report: &mut Report, message: &Message) {
     if message.header.kind.is_none() {
-        report.add_violation(Box::new(TypeRequired));
+        report.add_violation(Box::new(TypeRequired { span: message.header.span }));
     }
 }
 
@@ -54,14 +60,19 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
         type_required(&mut report, &message);
         assert_eq!(report.len(), 0);
 
-        let message: Message =
-            Message { header: Header::from("my feature"), body: None, footers: footer_vec![] };
+        let message: Message = Message {
+            header: Header::from("my feature"),
+            body: None,
+            body_span: None,
+            footers: footer_vec![],
+        };
 
         type_required(&mut report, &message);
         assert_eq!(report.len(), 1);
@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -25,6 +25,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct MaxLength {
     pub(crate) max_length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for MaxLength {
@@ -36,6 +37,10 @@ impl Violation for MaxLength {
         let max_length = self.max_length;
         format!("Header is longer than {max_length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for long body
@@ -45,7 +50,7 @@ pub(crate) fn max_length(report: &mut Report, message: &Message, length: usize)
     }
 
     if message.header.len() > length {
-        let violation = Box::new(MaxLength { max_length: length });
+        let violation = Box::new(MaxLength { max_length: length, span: message.header.span });
         report.add_violation(violation);
     }
 }
@@ -63,6 +68,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -22,6 +22,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct MinLength {
     pub(crate) length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for MinLength {
@@ -33,6 +34,10 @@ impl Violation for MinLength {
         let length = self.length;
         format!("Header is shorter than {length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for short body
@@ -42,7 +47,7 @@ pub(crate) fn min_length(report: &mut Report, message: &Message, length: usize)
     }
 
     if message.header.len() < length {
-        report.add_violation(Box::new(MinLength { length }));
+        report.add_violation(Box::new(MinLength { length, span: message.header.span }));
     }
 }
 
@@ -56,8 +61,12 @@ mod tests {
     fn test_min_length() {
         let mut report = Report::default();
 
-        let message: Message =
-            Message { header: Header::from("test"), body: None, footers: footer_vec![] };
+        let message: Message = Message {
+            header: Header::from("test"),
+            body: None,
+            body_span: None,
+            footers: footer_vec![],
+        };
 
         min_length(&mut report, &message, 4);
         assert_eq!(report.len(), 0);
@@ -25,3 +25,5 @@ pub(crate) use {
     },
     settings::Settings,
 };
+
+pub use scope::{AllowedScopes, ScopeRequirement};
@@ -12,3 +12,5 @@ pub(crate) use {
     scope_min_length::{scope_min_length, ScopeMinLength},
     scope_required::{scope_required, ScopeRequired},
 };
+
+pub use {scope_enum::AllowedScopes, scope_required::ScopeRequirement};
@@ -2,7 +2,7 @@ use crate::case::IdentifierCase;
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -24,6 +24,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct ScopeCase {
     pub(crate) case: IdentifierCase,
+    span: Option<Span>,
 }
 
 impl Violation for ScopeCase {
@@ -35,13 +36,18 @@ impl Violation for ScopeCase {
         let case = self.case;
         format!("Scope case is inconsistent. Expected: {case}")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for scope case consistency
 pub(crate) fn scope_case(report: &mut Report, message: &Message, case: IdentifierCase) {
     for scope in message.header.scope.iter() {
         if !case.is_match(scope) {
-            report.add_violation(Box::new(ScopeCase { case }));
+            report
+                .add_violation(Box::new(ScopeCase { case, span: message.header.scope.span }));
             return;
         }
     }
@@ -60,6 +66,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat(db-core, ui-core, req-internal): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -69,6 +76,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat(DB_Core, UICore, req-internal): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
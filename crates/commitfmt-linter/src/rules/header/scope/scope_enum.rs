@@ -1,11 +1,40 @@
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
+/// Scopes that are allowed for all types or for each type separately.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AllowedScopes {
+    All(Vec<Box<str>>),
+    /// Types that are not listed are not restricted.
+    ByType(Vec<(Box<str>, Vec<Box<str>>)>),
+}
+
+impl Default for AllowedScopes {
+    fn default() -> Self {
+        AllowedScopes::All(Vec::new())
+    }
+}
+
+impl AllowedScopes {
+    /// Returns the scopes allowed for the commit type.
+    /// `None` if scopes of the type are not restricted.
+    pub fn for_type(&self, kind: Option<&str>) -> Option<&[Box<str>]> {
+        match self {
+            AllowedScopes::All(scopes) => Some(scopes),
+            AllowedScopes::ByType(types) => {
+                let kind = kind?;
+                types.iter().find(|(name, _)| **name == *kind).map(|(_, scopes)| &scopes[..])
+            }
+        }
+    }
+}
+
 /// ## What it does
-/// Checks that the character case of the commit scope is consistent
+/// Checks that the commit scopes are in the allowed list.
+/// The list can be set for each commit type separately.
 ///
 /// ## Why is this bad?
 /// Scopes are used to categorize commits into groups based on the domain of the change.
@@ -23,6 +52,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct ScopeEnum {
     miss: String,
+    span: Option<Span>,
 }
 
 impl Violation for ScopeEnum {
@@ -34,13 +64,23 @@ impl Violation for ScopeEnum {
         let miss = &self.miss;
         format!("Scope is not allowed: {miss}")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
-/// Checks for scope case consistency
-pub(crate) fn scope_enum(report: &mut Report, message: &Message, allowed: &[Box<str>]) {
+/// Checks that scopes are allowed for the commit type
+pub(crate) fn scope_enum(report: &mut Report, message: &Message, allowed: &AllowedScopes) {
+    let Some(allowed) = allowed.for_type(message.header.kind.as_deref()) else {
+        return;
+    };
     for scope in message.header.scope.iter() {
         if !allowed.contains(scope) {
-            report.add_violation(Box::new(ScopeEnum { miss: scope.to_string() }));
+            report.add_violation(Box::new(ScopeEnum {
+                miss: scope.to_string(),
+                span: message.header.scope.span,
+            }));
             return;
         }
     }
@@ -56,11 +96,12 @@ mod tests {
     fn test_scope_enum() {
         let mut report = Report::default();
         let allowed_str = ["db", "ui"];
-        let allowed: Vec<Box<str>> = allowed_str.iter().map(|s| Box::from(*s)).collect();
+        let allowed = AllowedScopes::All(allowed_str.iter().map(|s| Box::from(*s)).collect());
 
         let message: Message = Message {
             header: Header::from("feat(db, ui): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
@@ -70,11 +111,32 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat(DB_Core, UICore): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
         scope_enum(&mut report, &message, &allowed);
         assert_eq!(report.len(), 1);
         assert_eq!(report.violations[0].rule_name(), "ScopeEnum");
+        assert_eq!(report.violations[0].span().unwrap().to_string(), "1:5-1:22");
+    }
+
+    #[test]
+    fn test_scope_enum_by_type() {
+        let allowed = AllowedScopes::ByType(vec![
+            ("feat".into(), vec!["api".into(), "web".into()]),
+            ("ci".into(), vec!["gha".into()]),
+        ]);
+        let check = |input: &str| {
+            let mut report = Report::default();
+            scope_enum(&mut report, &Message::parse(input, None, None), &allowed);
+            report.len()
+        };
+
+        assert_eq!(check("feat(api): my feature"), 0);
+        assert_eq!(check("ci(gha): update pipeline"), 0);
+        assert_eq!(check("docs(anything): update readme"), 0);
+        assert_eq!(check("feat(gha): my feature"), 1);
+        assert_eq!(check("ci(api, gha): update pipeline"), 1);
     }
 }
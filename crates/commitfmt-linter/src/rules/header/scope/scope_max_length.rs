@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -23,6 +23,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct ScopeMaxLength {
     pub(crate) length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for ScopeMaxLength {
@@ -34,6 +35,10 @@ impl Violation for ScopeMaxLength {
         let length = self.length;
         format!("Scope is longer than {length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for scope maximum length
@@ -45,7 +50,10 @@ pub(crate) fn scope_max_length(report: &mut Report, message: &Message, length: u
     let scope_length =
         message.header.scope.iter().map(|scope| scope.chars().count()).sum::<usize>();
     if scope_length > length {
-        report.add_violation(Box::new(ScopeMaxLength { length }));
+        report.add_violation(Box::new(ScopeMaxLength {
+            length,
+            span: message.header.scope.span,
+        }));
     }
 }
 
@@ -62,6 +70,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat(db, ui): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         scope_max_length(&mut report, &message, 4);
@@ -73,6 +82,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         scope_max_length(&mut report, &message, 10);
@@ -81,6 +91,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat(db-core, ui-core, req-internal): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         scope_max_length(&mut report, &message, 10);
@@ -93,6 +104,7 @@ mod tests {
         let message = Message {
             header: Header::from("feat(é, 界): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         let mut report = Report::default();
@@ -1,7 +1,7 @@
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
 /// ## What it does
@@ -22,6 +22,7 @@ use commitfmt_macros::ViolationMetadata;
 #[derive(ViolationMetadata)]
 pub(crate) struct ScopeMinLength {
     pub(crate) length: usize,
+    span: Option<Span>,
 }
 
 impl Violation for ScopeMinLength {
@@ -33,6 +34,10 @@ impl Violation for ScopeMinLength {
         let length = self.length;
         format!("Scope is shorter than {length} characters")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 /// Checks for scope minimum length
@@ -44,7 +49,10 @@ pub(crate) fn scope_min_length(report: &mut Report, message: &Message, length: u
     let scope_length =
         message.header.scope.iter().map(|scope| scope.chars().count()).sum::<usize>();
     if scope_length < length {
-        report.add_violation(Box::new(ScopeMinLength { length }));
+        report.add_violation(Box::new(ScopeMinLength {
+            length,
+            span: message.header.scope.span,
+        }));
     }
 }
 
@@ -61,6 +69,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat(db-core, ui-core): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         scope_min_length(&mut report, &message, 5);
@@ -72,6 +81,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         scope_min_length(&mut report, &message, 5);
@@ -80,6 +90,7 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat(db, ui): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         scope_min_length(&mut report, &message, 5);
@@ -92,6 +103,7 @@ mod tests {
         let message = Message {
             header: Header::from("feat(é, 界): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
         let mut report = Report::default();
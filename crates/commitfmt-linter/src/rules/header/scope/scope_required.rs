@@ -1,11 +1,32 @@
 use crate::report::Report;
 use crate::rules::LinterGroup;
 use crate::violation::{Violation, ViolationMetadata};
-use commitfmt_cc::Message;
+use commitfmt_cc::{Message, Span};
 use commitfmt_macros::ViolationMetadata;
 
+/// Types of commits that require a scope.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum ScopeRequirement {
+    #[default]
+    All,
+    ByType(Vec<Box<str>>),
+}
+
+impl ScopeRequirement {
+    /// Checks that the scope is required for the commit type.
+    pub fn is_required(&self, kind: Option<&str>) -> bool {
+        match self {
+            ScopeRequirement::All => true,
+            ScopeRequirement::ByType(types) => {
+                kind.is_some_and(|kind| types.iter().any(|name| **name == *kind))
+            }
+        }
+    }
+}
+
 /// ## What it does
 /// Checks that the commit scope is exists.
+/// The requirement can be set for each commit type separately.
 ///
 /// ## Why is this bad?
 /// Insufficient Scope can make it difficult to understand the domain of change.
@@ -20,7 +41,9 @@ use commitfmt_macros::ViolationMetadata;
 /// feat(ui): my feature
 /// ```
 #[derive(ViolationMetadata)]
-pub(crate) struct ScopeRequired;
+pub(crate) struct ScopeRequired {
+    span: Option<Span>,
+}
 
 impl Violation for ScopeRequired {
     fn group(&self) -> LinterGroup {
@@ -31,12 +54,23 @@ impl Violation for ScopeRequired {
     fn message(&self) -> String {
         format!("Scope is required")
     }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
-/// Checks for scope case consistency
-pub(crate) fn scope_required(report: &mut Report, message: &Message) {
+/// Checks that the scope exists if it's required for the commit type
+pub(crate) fn scope_required(
+    report: &mut Report,
+    message: &Message,
+    requirement: &ScopeRequirement,
+) {
+    if !requirement.is_required(message.header.kind.as_deref()) {
+        return;
+    }
     if message.header.scope.is_empty() {
-        report.add_violation(Box::new(ScopeRequired));
+        report.add_violation(Box::new(ScopeRequired { span: message.header.span }));
     }
 }
 
@@ -53,20 +87,36 @@ mod tests {
         let message: Message = Message {
             header: Header::from("feat(ui): my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
-        scope_required(&mut report, &message);
+        scope_required(&mut report, &message, &ScopeRequirement::All);
         assert_eq!(report.len(), 0);
 
         let message: Message = Message {
             header: Header::from("feat: my feature"),
             body: None,
+            body_span: None,
             footers: footer_vec![],
         };
 
-        scope_required(&mut report, &message);
+        scope_required(&mut report, &message, &ScopeRequirement::All);
         assert_eq!(report.len(), 1);
         assert_eq!(report.violations[0].rule_name(), "ScopeRequired");
     }
+
+    #[test]
+    fn test_scope_required_by_type() {
+        let requirement = ScopeRequirement::ByType(vec!["feat".into()]);
+        let check = |input: &str| {
+            let mut report = Report::default();
+            scope_required(&mut report, &Message::parse(input, None, None), &requirement);
+            report.len()
+        };
+
+        assert_eq!(check("feat(api): my feature"), 0);
+        assert_eq!(check("docs: update readme"), 0);
+        assert_eq!(check("feat: my feature"), 1);
+    }
 }
@@ -1,13 +1,16 @@
 use crate::case::{IdentifierCase, TextCase};
 
-#[derive(Debug, PartialEq, Default)]
+use super::{AllowedScopes, ScopeRequirement};
+
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Settings {
     pub max_length: usize,
     pub min_length: usize,
     pub scope_max_length: usize,
     pub scope_min_length: usize,
     pub scope_case: IdentifierCase,
-    pub scope_enum: Vec<Box<str>>,
+    pub scope_enum: AllowedScopes,
+    pub scope_required: ScopeRequirement,
     pub description_case: TextCase,
     pub description_max_length: usize,
     pub description_min_length: usize,
@@ -8,6 +8,8 @@ pub mod header;
 pub use linter_group::LinterGroup;
 pub use names::Rule;
 
+use crate::rule_set::RuleSet;
+
 #[inline]
 pub(crate) fn longer_than_chars(value: &str, length: usize) -> bool {
     value.len() > length && value.chars().nth(length).is_some()
@@ -18,13 +20,23 @@ pub(crate) fn shorter_than_chars(value: &str, length: usize) -> bool {
     length > 0 && (value.len() < length || value.chars().nth(length - 1).is_none())
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq)]
 pub struct Settings {
     pub body: body::Settings,
     pub header: header::Settings,
     pub footer: footer::Settings,
 }
 
+/// Rules that are applied to messages of the commit type instead of the base ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeOverride {
+    pub kind: Box<str>,
+    pub set: RuleSet,
+    /// Rules whose violations are reported as warnings
+    pub warnings: RuleSet,
+    pub settings: Settings,
+}
+
 #[cfg(test)]
 mod tests {
     use super::{longer_than_chars, shorter_than_chars};
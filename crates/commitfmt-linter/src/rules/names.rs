@@ -1,8 +1,12 @@
 use crate::rules::LinterGroup;
 
-#[allow(unused_imports)]
-use crate::rules::body;
-use crate::violation::Violation;
+use crate::rules::{body, footer, header};
+use crate::violation::{FixMode, Violation};
+
+/// Fix mode of each rule in the `(group, rule, fix mode)` form.
+/// The table is generated by build.rs from the `fix_mode` implementations.
+const FIX_MODES: &[(&str, &str, FixMode)] =
+    include!(concat!(env!("OUT_DIR"), "/fix_modes.rs"));
 
 #[commitfmt_macros::map_names]
 pub fn name_to_rule(linter: Linter, code: &str) -> Option<Rule> {
@@ -39,11 +43,68 @@ pub fn name_to_rule(linter: Linter, code: &str) -> Option<Rule> {
         (Body, "min-length")                  => body::MinLength,
         // Footer
         (Footer, "breaking-exclamation")      => footer::BreakingExclamation,
+        (Footer, "co-authored-by")            => footer::CoAuthoredBy,
         (Footer, "exists")                    => footer::Exists,
         (Footer, "key-case")                  => footer::KeyCase,
+        (Footer, "key-enum")                  => footer::KeyEnum,
         (Footer, "max-length")                => footer::MaxLength,
         (Footer, "max-line-length")           => footer::MaxLineLength,
         (Footer, "min-length")                => footer::MinLength,
+        (Footer, "signed-off-by")             => footer::SignedOffBy,
+        (Footer, "value-pattern")             => footer::ValuePattern,
         _ => return None
     })
 }
+
+impl Rule {
+    /// Returns all rules.
+    pub fn all() -> impl Iterator<Item = Rule> {
+        (0..Rule::COUNT).filter_map(Rule::from_u8)
+    }
+
+    /// Returns the rule by its id in the `group.rule` form, e.g. `header.type-enum`.
+    pub fn from_id(id: &str) -> Option<Self> {
+        let (group, name) = id.split_once('.')?;
+        Rule::from_name(LinterGroup::from_name(group)?, name)
+    }
+
+    /// Returns the rule id in the `group.rule` form.
+    pub fn id(&self) -> String {
+        format!("{}.{}", self.group().as_display(), self.as_display())
+    }
+
+    /// Returns the best fix mode of the rule violations.
+    /// Some violations of the rule can be less fixable.
+    pub fn fix_mode(&self) -> FixMode {
+        let group = self.group().as_display();
+        FIX_MODES
+            .iter()
+            .find(|(fix_group, name, _)| *fix_group == group && *name == self.as_display())
+            .map_or(FixMode::Unfixable, |(_, _, fix_mode)| *fix_mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_id() {
+        assert_eq!(Rule::HeaderTypeEnum.id(), "header.type-enum");
+        assert_eq!(Rule::from_id("header.type-enum"), Some(Rule::HeaderTypeEnum));
+        assert_eq!(Rule::from_id("footer.max-length"), Some(Rule::FooterMaxLength));
+        assert_eq!(Rule::from_id("type-enum"), None);
+        assert_eq!(Rule::from_id("header.unknown"), None);
+        assert!(Rule::all().all(|rule| Rule::from_id(&rule.id()) == Some(rule)));
+    }
+
+    #[test]
+    fn test_rule_metadata() {
+        assert_eq!(Rule::all().count(), usize::from(Rule::COUNT));
+        assert_eq!(Rule::BodyMinLength.group(), LinterGroup::Body);
+        assert_eq!(Rule::HeaderDescriptionFullStop.fix_mode(), FixMode::Safe);
+        assert_eq!(Rule::BodyFullStop.fix_mode(), FixMode::Unsafe);
+        assert_eq!(Rule::HeaderTypeEnum.fix_mode(), FixMode::Unfixable);
+        assert!(Rule::HeaderTypeEnum.explanation().starts_with("## What it does"));
+    }
+}
@@ -1,4 +1,4 @@
-use commitfmt_cc::Message;
+use commitfmt_cc::{Edit, Message, Span, SyntaxTree};
 use thiserror::Error;
 
 use crate::rules::LinterGroup;
@@ -17,13 +17,42 @@ pub enum ViolationError {
 }
 
 /// The fix mode of a violation
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FixMode {
     Safe,
     Unsafe,
     Unfixable,
 }
 
+impl FixMode {
+    pub fn as_display(&self) -> &'static str {
+        match self {
+            FixMode::Safe => "safe",
+            FixMode::Unsafe => "unsafe",
+            FixMode::Unfixable => "unfixable",
+        }
+    }
+}
+
+/// The severity of a violation
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The violation fails the check
+    #[default]
+    Error,
+    /// The violation is reported, but doesn't fail the check
+    Warning,
+}
+
+impl Severity {
+    pub fn as_display(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
 pub trait ViolationMetadata {
     /// Returns the rule name of this violation
     fn rule_name(&self) -> &'static str;
@@ -40,6 +69,12 @@ pub trait Violation: ViolationMetadata {
     /// Returns the linter group of this violation
     fn group(&self) -> LinterGroup;
 
+    /// Location of the offending text in the parsed message.
+    /// `None` if the violation is not tied to a specific part of the message.
+    fn span(&self) -> Option<Span> {
+        None
+    }
+
     /// Whether the violation is fixable
     fn fix_mode(&self) -> FixMode {
         FixMode::Unfixable
@@ -49,6 +84,12 @@ pub trait Violation: ViolationMetadata {
     fn fix(&self, _: &mut Message) -> Result<(), ViolationError> {
         Err(ViolationError::Unfixable())
     }
+
+    /// Returns minimal edits of the source text that fix the violation.
+    /// `None` if the violation can only be fixed on the parsed message.
+    fn edits(&self, _: &SyntaxTree) -> Option<Vec<Edit>> {
+        None
+    }
 }
 
 impl std::fmt::Display for Box<dyn Violation> {
@@ -97,6 +138,20 @@ mod tests {
         assert!(violation.fix(&mut Message::default()).is_err());
     }
 
+    #[test]
+    fn test_fix_mode_display() {
+        assert_eq!(FixMode::Safe.as_display(), "safe");
+        assert_eq!(FixMode::Unsafe.as_display(), "unsafe");
+        assert_eq!(FixMode::Unfixable.as_display(), "unfixable");
+    }
+
+    #[test]
+    fn test_severity_display() {
+        assert_eq!(Severity::default(), Severity::Error);
+        assert_eq!(Severity::Error.as_display(), "error");
+        assert_eq!(Severity::Warning.as_display(), "warning");
+    }
+
     #[test]
     fn test_display() {
         let violation = TestViolation;
@@ -11,6 +11,7 @@ struct Rule {
     linter: Ident,
     name: LitStr,
     struct_name: Ident,
+    path: Path,
 }
 
 impl Parse for Rule {
@@ -36,7 +37,7 @@ impl Parse for Rule {
         let last_seg = &path.segments.last().unwrap().ident;
         let variant_name = format_ident!("{}{}", linter, last_seg);
 
-        Ok(Self { variant_name, linter, name, struct_name: last_seg.clone() })
+        Ok(Self { variant_name, linter, name, struct_name: last_seg.clone(), path })
     }
 }
 
@@ -109,6 +110,26 @@ pub(crate) fn map_names(func: &ItemFn) -> syn::Result<TokenStream> {
         }
     });
 
+    // Build each match arm, Rule::BodyLeadingNewLine => Body
+    let rule_to_linter_matches = rules.iter().map(|rule| {
+        let linter_ident = &rule.linter;
+        let variant_ident = &rule.variant_name;
+
+        quote! {
+            Rule::#variant_ident => #linter_ident
+        }
+    });
+
+    // Build each match arm, Rule::BodyLeadingNewLine => body::LeadingNewLine::EXPLANATION
+    let rule_to_explanation_matches = rules.iter().map(|rule| {
+        let variant_ident = &rule.variant_name;
+        let path = &rule.path;
+
+        quote! {
+            Rule::#variant_ident => #path::EXPLANATION
+        }
+    });
+
     let rule_count = u8::try_from(rules.len())
         .map_err(|_| Error::new(func.span(), "expected at most 255 rules"))?;
     let rule_count = proc_macro2::Literal::u8_unsuffixed(rule_count);
@@ -146,6 +167,23 @@ pub(crate) fn map_names(func: &ItemFn) -> syn::Result<TokenStream> {
                 }
             }
 
+            pub fn group(&self) -> LinterGroup {
+                #![allow(clippy::enum_glob_use)]
+                use LinterGroup::*;
+
+                match self {
+                    #(#rule_to_linter_matches),*
+                }
+            }
+
+            /// Returns the explanation of what the rule catches,
+            /// why it's bad, and what users should do instead.
+            pub fn explanation(&self) -> &'static str {
+                match self {
+                    #(#rule_to_explanation_matches),*
+                }
+            }
+
             pub fn from_name(linter: LinterGroup, name: &str) -> Option<Self> {
                 #![allow(clippy::enum_glob_use)]
                 use LinterGroup::*;
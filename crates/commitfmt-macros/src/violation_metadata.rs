@@ -8,6 +8,12 @@ pub(crate) fn violation_metadata(input: DeriveInput) -> Result<TokenStream, Erro
     let name = input.ident;
 
     Ok(quote! {
+        #[automatically_derived]
+        impl #name {
+            /// Explanation of the rule from the doc comment.
+            pub(crate) const EXPLANATION: &'static str = #docs;
+        }
+
         #[automatically_derived]
         #[allow(deprecated)]
         impl ViolationMetadata for #name {
@@ -16,7 +22,7 @@ pub(crate) fn violation_metadata(input: DeriveInput) -> Result<TokenStream, Erro
             }
 
             fn explain(&self) -> Option<&'static str> {
-                Some(#docs)
+                Some(Self::EXPLANATION)
             }
         }
     })
@@ -106,5 +112,6 @@ mod tests {
         let input: DeriveInput = syn::parse2(input).unwrap();
         let docs = violation_metadata(input).unwrap();
         assert!(docs.to_string().contains("fn rule_name"));
+        assert!(docs.to_string().contains("const EXPLANATION"));
     }
 }
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, process::Command};
+use std::{collections::HashMap, fmt, process::Command};
 
 use crate::{Error, Result};
 use nom::{
@@ -98,6 +98,24 @@ impl Template {
     }
 }
 
+impl fmt::Display for Template {
+    /// Writes the template back in the source form, with normalized tag spacing.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => f.write_str(literal)?,
+                Segment::Command(command) => {
+                    write!(f, "{TAG_CMD_START} {command} {TAG_END}")?;
+                }
+                Segment::Variable(variable) => {
+                    write!(f, "{TAG_VARIABLE_START} {variable} {TAG_END}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 fn execute_command(command: &str) -> Result<String> {
     let output = Command::new("sh").arg("-c").arg(command).output()?;
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
@@ -186,6 +204,12 @@ mod tests {
         assert_eq!(segments[1], &Segment::Command("date".to_string()));
     }
 
+    #[test]
+    fn test_display() {
+        let template = Template::parse("Hello ${{NAME}}, today is {{  date }}!").unwrap();
+        assert_eq!(template.to_string(), "Hello ${{ NAME }}, today is {{ date }}!");
+    }
+
     #[test]
     fn test_parse_mixed_template() {
         let template = Template::parse("Hello ${{ NAME }}, today is {{ date }}!").unwrap();
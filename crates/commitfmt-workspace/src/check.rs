@@ -0,0 +1,433 @@
+use std::fmt;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use regex_lite::Regex;
+use serde::Deserialize;
+use toml::de::{DeTable, DeValue, ValueDeserializer};
+use toml::{Spanned, Value};
+
+use commitfmt_cc::Footer;
+use commitfmt_linter::rules::{LinterGroup, Settings};
+
+use crate::configuration::{CommitConfiguration, CommitParams, MAX_CONFIG_SIZE};
+use crate::rules::parse_rule;
+use crate::settings::{AdditionalFooter, CommitSettings, OVERRIDES_KEY, SKIP_KEY};
+use crate::Error;
+
+/// Line and column of a problem in the config file, both starting from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// Returns the location of the byte offset in the source.
+    fn from_offset(source: &str, offset: usize) -> Self {
+        let before = &source[..offset.min(source.len())];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+/// Problem found in a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    pub path: PathBuf,
+    /// Location of the problem, if it's related to a value of the file
+    pub location: Option<Location>,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location {
+            Some(Location { line, column }) => {
+                write!(f, "{}:{line}:{column}: {}", self.path.display(), self.message)
+            }
+            None => write!(f, "{}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+/// Result of the config check.
+#[derive(Debug, Default)]
+pub struct ConfigCheck {
+    /// Checked config files, the extended one goes first
+    pub files: Vec<PathBuf>,
+    pub problems: Vec<ConfigProblem>,
+    /// Effective settings, if there are no problems
+    pub settings: Option<CommitSettings>,
+}
+
+/// Validates the effective configuration of the directory.
+///
+/// Unlike [`open_settings`](crate::open_settings), all problems of the config files
+/// are collected with their locations instead of stopping at the first one.
+/// The directory without a config file has the default settings.
+pub fn check_config(dir_path: &Path) -> ConfigCheck {
+    let Ok(config_path) = CommitParams::find_config_path(dir_path) else {
+        return ConfigCheck {
+            settings: Some(CommitSettings::default()),
+            ..Default::default()
+        };
+    };
+
+    let mut check = ConfigCheck::default();
+    let mut file = FileCheck::new(&config_path);
+    let extends = file.check();
+
+    if let Some(extends) = extends {
+        let parent_path = config_path.parent().unwrap().join(extends.get_ref());
+        if parent_path.is_file() {
+            let mut parent_file = FileCheck::new(&parent_path);
+            parent_file.check();
+            check.problems.extend(parent_file.problems);
+            check.files.push(parent_path);
+        } else {
+            let err = Error::ConfigNotFound(parent_path.to_string_lossy().to_string());
+            file.report(Some(extends.span()), err);
+        }
+    }
+    check.files.push(config_path.clone());
+    check.problems.extend(file.problems);
+
+    if check.problems.is_empty() {
+        match CommitParams::open(&config_path).and_then(CommitSettings::from_params) {
+            Ok(settings) => check.settings = Some(settings),
+            Err(err) => check.problems.push(ConfigProblem {
+                path: config_path,
+                location: None,
+                message: err.to_string(),
+            }),
+        }
+    }
+
+    check
+}
+
+/// Problems of a single config file.
+struct FileCheck<'a> {
+    path: &'a Path,
+    source: String,
+    problems: Vec<ConfigProblem>,
+}
+
+impl<'a> FileCheck<'a> {
+    fn new(path: &'a Path) -> Self {
+        Self { path, source: String::new(), problems: Vec::new() }
+    }
+
+    /// Adds the problem at the span of the source.
+    fn report(&mut self, span: Option<Range<usize>>, message: impl fmt::Display) {
+        let location = span.map(|span| Location::from_offset(&self.source, span.start));
+        self.problems.push(ConfigProblem {
+            path: self.path.to_path_buf(),
+            location,
+            message: message.to_string(),
+        });
+    }
+
+    /// Checks the file and returns the `extends` value, if any.
+    fn check(&mut self) -> Option<Spanned<String>> {
+        match std::fs::metadata(self.path) {
+            Ok(metadata) if metadata.len() > MAX_CONFIG_SIZE => {
+                self.report(None, Error::FileTooLarge);
+                return None;
+            }
+            Ok(_) => {}
+            Err(err) => {
+                self.report(None, Error::IOError(err));
+                return None;
+            }
+        }
+        match std::fs::read_to_string(self.path) {
+            Ok(source) => self.source = source,
+            Err(err) => {
+                self.report(None, Error::IOError(err));
+                return None;
+            }
+        }
+
+        let source = self.source.clone();
+        let root = match DeTable::parse(&source) {
+            Ok(root) => root.into_inner(),
+            Err(err) => {
+                self.report(err.span(), Error::ParseError(err.message().to_string()));
+                return None;
+            }
+        };
+        let config = match toml::from_str::<CommitConfiguration>(&source) {
+            Ok(config) => config,
+            Err(err) => {
+                self.report(err.span(), Error::ParseError(err.message().to_string()));
+                return None;
+            }
+        };
+
+        if let Some(lint) = root.get("lint").and_then(|lint| lint.get_ref().as_table()) {
+            self.check_lint(lint);
+        }
+        if let Some(key) = &config.breaking_key {
+            if !Footer::is_breaking_key(key) {
+                self.report(
+                    span_of(&root, "breaking-key"),
+                    Error::InvalidBreakingKey(key.clone()),
+                );
+            }
+        }
+        if let Some(ignore) = root.get("ignore").and_then(|ignore| ignore.get_ref().as_table())
+        {
+            self.check_patterns(ignore, "patterns");
+            self.check_patterns(ignore, "authors");
+        }
+        self.check_footers(&root, &config);
+
+        let extends_span = span_of(&root, "extends")?;
+        config.extends.map(|extends| Spanned::new(extends_span, extends))
+    }
+
+    /// Checks the linter group tables and the overrides by commit type.
+    fn check_lint(&mut self, lint: &DeTable) {
+        self.check_groups(lint);
+
+        let Some(overrides) = lint.get(OVERRIDES_KEY) else {
+            return;
+        };
+        let Some(overrides_table) = overrides.get_ref().as_table() else {
+            let err =
+                Error::UnexpectedFieldType(OVERRIDES_KEY.to_string(), "table".to_string());
+            self.report(Some(overrides.span()), err);
+            return;
+        };
+        for (kind, value) in overrides_table {
+            let Some(override_table) = value.get_ref().as_table() else {
+                let err = Error::UnexpectedFieldType(
+                    format!("{OVERRIDES_KEY}.{}", kind.get_ref()),
+                    "table".to_string(),
+                );
+                self.report(Some(value.span()), err);
+                continue;
+            };
+            self.check_groups(override_table);
+
+            if let Some(skip) = override_table.get(SKIP_KEY) {
+                if !skip.get_ref().is_bool() {
+                    let err =
+                        Error::UnexpectedFieldType(SKIP_KEY.to_string(), "bool".to_string());
+                    self.report(Some(skip.span()), err);
+                }
+            }
+        }
+    }
+
+    /// Checks the rules of linter group tables (e.g. `header`) of the table.
+    fn check_groups(&mut self, table: &DeTable) {
+        for linter in LinterGroup::iter() {
+            let Some(group) = table.get(linter.as_display()) else {
+                continue;
+            };
+            let Some(group_table) = group.get_ref().as_table() else {
+                let err = Error::UnexpectedFieldType(
+                    linter.as_display().to_string(),
+                    "table".to_string(),
+                );
+                self.report(Some(group.span()), err);
+                continue;
+            };
+
+            for (key, value) in group_table {
+                let result = to_value(value).and_then(|rule_value| {
+                    parse_rule(&mut Settings::default(), linter, key.get_ref(), &rule_value)
+                });
+                match result {
+                    Ok(_) => {}
+                    Err(err @ Error::UnknownRule(..)) => self.report(Some(key.span()), err),
+                    Err(err) => self.report(Some(value.span()), err),
+                }
+            }
+        }
+    }
+
+    /// Checks the regular expressions of the `ignore` table array.
+    fn check_patterns(&mut self, ignore: &DeTable, key: &str) {
+        let Some(patterns) = ignore.get(key).and_then(|value| value.get_ref().as_array())
+        else {
+            return;
+        };
+        for pattern in patterns {
+            let Some(source) = pattern.get_ref().as_str() else {
+                continue;
+            };
+            if let Err(err) = Regex::new(source) {
+                self.report(Some(pattern.span()), Error::InvalidPattern(err));
+            }
+        }
+    }
+
+    /// Checks the additional footers, the problem is located at the field that caused it.
+    fn check_footers(&mut self, root: &DeTable, config: &CommitConfiguration) {
+        let (Some(tables), Some(configs)) = (
+            root.get("additional-footers").and_then(|value| value.get_ref().as_array()),
+            &config.additional_footers,
+        ) else {
+            return;
+        };
+
+        for (table, footer_config) in tables.iter().zip(configs) {
+            let Err(err) = AdditionalFooter::from_config(footer_config.clone()) else {
+                continue;
+            };
+            let field = match err {
+                Error::InvalidPattern(_) => "branch-pattern",
+                Error::UnknownOnConflictAction(_) => "on-conflict",
+                _ => "value",
+            };
+            let span = table
+                .get_ref()
+                .as_table()
+                .and_then(|footer_table| span_of(footer_table, field))
+                .unwrap_or_else(|| table.span());
+            self.report(Some(span), err);
+        }
+    }
+}
+
+/// Returns the span of the value of the table key.
+fn span_of(table: &DeTable, key: &str) -> Option<Range<usize>> {
+    table.get(key).map(Spanned::span)
+}
+
+/// Converts the spanned value to the TOML value.
+fn to_value(value: &Spanned<DeValue>) -> crate::Result<Value> {
+    Ok(Value::deserialize(ValueDeserializer::from(value.clone()))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use commitfmt_linter::Rule;
+
+    use super::*;
+
+    fn check_files(files: &[(&str, &str)]) -> ConfigCheck {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, content) in files {
+            fs::write(dir.path().join(name), content).unwrap();
+        }
+        check_config(dir.path())
+    }
+
+    fn locations(check: &ConfigCheck) -> Vec<(String, usize, usize)> {
+        check
+            .problems
+            .iter()
+            .map(|problem| {
+                let name = problem.path.file_name().unwrap().to_string_lossy().to_string();
+                let location = problem.location.unwrap();
+                (name, location.line, location.column)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_location_from_offset() {
+        let source = "a = 1\nbé = 2\n";
+        assert_eq!(Location::from_offset(source, 0), Location { line: 1, column: 1 });
+        assert_eq!(Location::from_offset(source, 6), Location { line: 2, column: 1 });
+        assert_eq!(Location::from_offset(source, 10), Location { line: 2, column: 4 });
+    }
+
+    #[test]
+    fn test_check_config_without_file() {
+        let check = check_files(&[]);
+        assert!(check.files.is_empty());
+        assert!(check.problems.is_empty());
+        assert_eq!(check.settings, Some(CommitSettings::default()));
+    }
+
+    #[test]
+    fn test_check_config_valid() {
+        let check = check_files(&[(
+            "commitfmt.toml",
+            "[lint.header]\ntype-enum = [\"feat\", \"fix\"]\n\n[[additional-footers]]\nkey = \"Ticket\"\nvalue = \"1\"\n",
+        )]);
+        assert!(check.problems.is_empty());
+        assert_eq!(check.files.len(), 1);
+
+        let settings = check.settings.unwrap();
+        assert!(settings.rules.set.contains(Rule::HeaderTypeEnum));
+        assert_eq!(settings.footers.borrow().len(), 1);
+    }
+
+    #[test]
+    fn test_check_config_syntax_error() {
+        let check =
+            check_files(&[("commitfmt.toml", "[lint.header]\ntype-enum = [\"feat\"\n")]);
+        assert_eq!(check.problems.len(), 1);
+        assert_eq!(check.problems[0].location.unwrap().line, 2);
+        assert!(check.settings.is_none());
+    }
+
+    #[test]
+    fn test_check_config_locations() {
+        let check = check_files(&[(
+            "commitfmt.toml",
+            "breaking-key = \"Breaking\"
+
+[lint.header]
+type-enum = 1
+unknown = true
+
+[lint.overrides.docs]
+skip = \"yes\"
+
+[ignore]
+patterns = [\"^Merge\", \"(\"]
+
+[[additional-footers]]
+key = \"Ticket\"
+value = \"1\"
+on-conflict = \"replace\"
+",
+        )]);
+
+        let name = "commitfmt.toml".to_string();
+        assert_eq!(
+            locations(&check),
+            [
+                (name.clone(), 4, 13),
+                (name.clone(), 5, 1),
+                (name.clone(), 8, 8),
+                (name.clone(), 1, 16),
+                (name.clone(), 11, 23),
+                (name, 16, 15),
+            ]
+        );
+        assert!(check.problems[1].to_string().ends_with("Unknown rule: header → unknown"));
+        assert!(check.settings.is_none());
+    }
+
+    #[test]
+    fn test_check_config_extends() {
+        let check = check_files(&[
+            ("base.toml", "[lint.body]\nmax-line-length = \"long\"\n"),
+            ("commitfmt.toml", "extends = \"base.toml\"\n\n[lint.header]\ntype-enum = true\n"),
+        ]);
+        assert_eq!(check.files.len(), 2);
+        assert!(check.files[0].ends_with("base.toml"));
+        assert_eq!(
+            locations(&check),
+            [("base.toml".to_string(), 2, 19), ("commitfmt.toml".to_string(), 4, 13)]
+        );
+
+        let check = check_files(&[("commitfmt.toml", "extends = \"missing.toml\"\n")]);
+        assert_eq!(locations(&check), [("commitfmt.toml".to_string(), 1, 11)]);
+    }
+}
@@ -11,7 +11,7 @@ const KNOWN_PATHS: &[&str] = &[".commitfmt.toml", "commitfmt.toml"];
 
 /// Maximum size of the config file
 /// If the file is larger than this, return an error.
-const MAX_CONFIG_SIZE: u64 = 1_000_000;
+pub(crate) const MAX_CONFIG_SIZE: u64 = 1_000_000;
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
@@ -31,6 +31,17 @@ pub(crate) struct LintConfiguration {
     pub unsafe_fixes: Option<bool>,
 }
 
+/// Ignore configuration.
+///
+/// Describes commits that are not checked.
+#[derive(Debug, PartialEq, Deserialize, Clone, Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct IgnoreConfiguration {
+    pub patterns: Option<Vec<String>>,
+    pub authors: Option<Vec<String>>,
+    pub merges: Option<bool>,
+}
+
 /// Commit configuration.
 ///
 /// This is used to configure the commit message.
@@ -40,7 +51,9 @@ pub(crate) struct CommitConfiguration {
     pub extends: Option<String>,
     pub footer_separators: Option<String>,
     pub comment_symbol: Option<String>,
+    pub breaking_key: Option<String>,
     pub lint: Option<LintConfiguration>,
+    pub ignore: Option<IgnoreConfiguration>,
     pub additional_footers: Option<Vec<AdditionalFooterConfig>>,
 }
 
@@ -66,7 +79,7 @@ impl CommitParams {
 
     /// Open a single configuration file without extending it
     /// and parse it into a `CommitParams` object
-    fn open_single(path: &Path) -> Result<Self> {
+    pub(crate) fn open_single(path: &Path) -> Result<Self> {
         if std::fs::metadata(path)?.len() > MAX_CONFIG_SIZE {
             return Err(Error::FileTooLarge);
         }
@@ -87,20 +100,31 @@ impl CommitParams {
     /// Open configuration from directory
     /// If the file contains an `extends` field, it will be used to extend the configuration.
     pub(crate) fn open(config_path: &Path) -> Result<CommitParams> {
+        let mut files = Self::open_files(config_path)?.into_iter();
+        let (_, mut params) = files.next().expect("the config file is opened");
+        for (_, child_params) in files {
+            params.extend(&child_params);
+        }
+
+        Ok(params)
+    }
+
+    /// Opens the config file and the config it extends, the parent goes first.
+    pub(crate) fn open_files(config_path: &Path) -> Result<Vec<(PathBuf, CommitParams)>> {
         if !config_path.is_file() || !config_path.exists() {
             return Err(Error::ConfigNotFound(config_path.to_string_lossy().to_string()));
         }
 
         let target_params = Self::open_single(config_path)?;
+        let mut files = Vec::new();
         if let Some(extends) = &target_params.config.extends {
             let parent_path = config_path.parent().unwrap().join(extends);
-            let mut parent_params = Self::open_single(&parent_path)?;
-            parent_params.extend(&target_params);
-
-            Ok(parent_params)
-        } else {
-            Ok(target_params)
+            let parent_params = Self::open_single(&parent_path)?;
+            files.push((parent_path, parent_params));
         }
+        files.push((config_path.to_path_buf(), target_params));
+
+        Ok(files)
     }
 }
 
@@ -111,10 +135,12 @@ impl CommitParams {
     /// Values from `other` take precedence and override existing settings:
     ///
     /// - `lint`: completely replaced with configuration from `other`, if present
+    /// - `ignore`: completely replaced with configuration from `other`, if present
     /// - `footers`: additional footers from `other` are appended to existing ones
     /// - `params`: parameters from `other` are merged with existing ones
     /// - `footer_separators`: completely replaced with configuration from `other`, if present
     /// - `comment_symbol`: completely replaced with configuration from `other`, if present
+    /// - `breaking_key`: completely replaced with configuration from `other`, if present
     ///
     /// The `extends` field is ignored and not processed.
     pub(crate) fn extend(&mut self, other: &CommitParams) {
@@ -122,6 +148,10 @@ impl CommitParams {
             self.config.lint = Some(other_lint.clone());
         }
 
+        if let Some(other_ignore) = &other.config.ignore {
+            self.config.ignore = Some(other_ignore.clone());
+        }
+
         if let Some(other_footers) = &other.config.additional_footers {
             if let Some(self_footers) = &mut self.config.additional_footers {
                 for footer in other_footers {
@@ -140,6 +170,10 @@ impl CommitParams {
             self.config.comment_symbol = Some(other_comment_symbol.clone());
         }
 
+        if let Some(other_breaking_key) = &other.config.breaking_key {
+            self.config.breaking_key = Some(other_breaking_key.clone());
+        }
+
         self.lint_values.extend(other.lint_values.clone());
     }
 }
@@ -202,9 +236,11 @@ alignment = \"right\"
             config: CommitConfiguration {
                 extends: Some("base".to_string()),
                 lint: Some(LintConfiguration { unsafe_fixes: Some(false) }),
+                ignore: None,
                 additional_footers: None,
                 footer_separators: None,
                 comment_symbol: None,
+                breaking_key: None,
             },
             lint_values: Map::new(),
         };
@@ -213,9 +249,11 @@ alignment = \"right\"
             config: CommitConfiguration {
                 extends: Some("ignored".to_string()),
                 lint: Some(LintConfiguration { unsafe_fixes: Some(true) }),
+                ignore: None,
                 additional_footers: None,
                 footer_separators: None,
                 comment_symbol: None,
+                breaking_key: None,
             },
             lint_values: Map::new(),
         };
@@ -232,6 +270,7 @@ alignment = \"right\"
             config: CommitConfiguration {
                 extends: None,
                 lint: None,
+                ignore: None,
                 additional_footers: Some(vec![AdditionalFooterConfig {
                     key: "existing".to_string(),
                     on_conflict: Some("error".to_string()),
@@ -242,6 +281,7 @@ alignment = \"right\"
                 }]),
                 footer_separators: None,
                 comment_symbol: None,
+                breaking_key: None,
             },
             lint_values: Map::new(),
         };
@@ -250,6 +290,7 @@ alignment = \"right\"
             config: CommitConfiguration {
                 extends: None,
                 lint: None,
+                ignore: None,
                 additional_footers: Some(vec![AdditionalFooterConfig {
                     key: "new".to_string(),
                     on_conflict: Some("skip".to_string()),
@@ -260,6 +301,7 @@ alignment = \"right\"
                 }]),
                 footer_separators: None,
                 comment_symbol: None,
+                breaking_key: None,
             },
             lint_values: Map::new(),
         };
@@ -283,9 +325,11 @@ alignment = \"right\"
             config: CommitConfiguration {
                 extends: None,
                 lint: None,
+                ignore: None,
                 additional_footers: None,
                 footer_separators: None,
                 comment_symbol: None,
+                breaking_key: None,
             },
             lint_values: Map::new(),
         };
@@ -294,6 +338,7 @@ alignment = \"right\"
             config: CommitConfiguration {
                 extends: None,
                 lint: None,
+                ignore: None,
                 additional_footers: Some(vec![AdditionalFooterConfig {
                     key: "first".to_string(),
                     on_conflict: None,
@@ -304,6 +349,7 @@ alignment = \"right\"
                 }]),
                 footer_separators: None,
                 comment_symbol: None,
+                breaking_key: None,
             },
             lint_values: Map::new(),
         };
@@ -366,9 +412,11 @@ alignment = \"right\"
             config: CommitConfiguration {
                 extends: Some("test".to_string()),
                 lint: Some(LintConfiguration { unsafe_fixes: Some(true) }),
+                ignore: None,
                 additional_footers: Some(vec![]),
                 footer_separators: None,
                 comment_symbol: None,
+                breaking_key: None,
             },
             lint_values: Map::new(),
         };
@@ -377,9 +425,11 @@ alignment = \"right\"
             config: CommitConfiguration {
                 extends: None,
                 lint: None,
+                ignore: None,
                 additional_footers: None,
                 footer_separators: None,
                 comment_symbol: None,
+                breaking_key: None,
             },
             lint_values: Map::new(),
         };
@@ -398,6 +448,7 @@ alignment = \"right\"
             config: CommitConfiguration {
                 extends: Some("base".to_string()),
                 lint: Some(LintConfiguration { unsafe_fixes: Some(false) }),
+                ignore: None,
                 additional_footers: Some(vec![AdditionalFooterConfig {
                     key: "base_footer".to_string(),
                     on_conflict: Some("error".to_string()),
@@ -408,6 +459,7 @@ alignment = \"right\"
                 }]),
                 footer_separators: None,
                 comment_symbol: None,
+                breaking_key: None,
             },
             lint_values: {
                 let mut map = Map::new();
@@ -420,6 +472,7 @@ alignment = \"right\"
             config: CommitConfiguration {
                 extends: Some("should_be_ignored".to_string()),
                 lint: Some(LintConfiguration { unsafe_fixes: Some(true) }),
+                ignore: None,
                 additional_footers: Some(vec![AdditionalFooterConfig {
                     key: "other_footer".to_string(),
                     on_conflict: Some("skip".to_string()),
@@ -430,6 +483,7 @@ alignment = \"right\"
                 }]),
                 footer_separators: None,
                 comment_symbol: None,
+                breaking_key: None,
             },
             lint_values: {
                 let mut map = Map::new();
@@ -0,0 +1,492 @@
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use commitfmt_cc::{Footer, Message};
+use commitfmt_linter::rules::LinterGroup;
+use commitfmt_linter::{Rule, RuleSet};
+
+use crate::configuration::{
+    AdditionalFooterConfig, CommitConfiguration, CommitParams, IgnoreConfiguration,
+};
+use crate::settings::{DEFAULT_IGNORE_PATTERNS, OVERRIDES_KEY};
+use crate::Result;
+
+/// Source of a value of the effective config.
+///
+/// The config file found in the directory is the child config, even without `extends`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    Default,
+    /// The config file extended with `extends`
+    Parent,
+    Child,
+    /// Fallback from the git config, e.g. `core.commentChar`
+    GitConfig,
+}
+
+impl Origin {
+    pub fn as_display(&self) -> &'static str {
+        match self {
+            Origin::Default => "default",
+            Origin::Parent => "parent config",
+            Origin::Child => "child config",
+            Origin::GitConfig => "git config",
+        }
+    }
+}
+
+/// Value of the effective config with its origin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OriginValue {
+    pub key: String,
+    pub value: Value,
+    pub origin: Origin,
+}
+
+/// Table of the effective config.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OriginTable {
+    /// Keys of the table, e.g. `["lint", "header"]`. The root table has no keys
+    pub path: Vec<String>,
+    /// Whether the table is an item of the array of tables, e.g. `[[additional-footers]]`
+    pub is_array_item: bool,
+    pub values: Vec<OriginValue>,
+}
+
+impl OriginTable {
+    fn new(path: &[&str]) -> Self {
+        Self {
+            path: path.iter().map(ToString::to_string).collect(),
+            is_array_item: false,
+            values: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, key: &str, value: Value, origin: Origin) {
+        self.values.push(OriginValue { key: key.to_string(), value, origin });
+    }
+}
+
+/// Configuration resolved from the config files with the origin of each value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveConfig {
+    /// Config files with their origins, the parent goes first
+    pub files: Vec<(PathBuf, Origin)>,
+    pub tables: Vec<OriginTable>,
+}
+
+impl EffectiveConfig {
+    /// Sets the root value that is not set in the config files, e.g. from the git config.
+    pub fn apply_fallback(&mut self, key: &str, value: &str, origin: Origin) {
+        let Some(root) = self.tables.iter_mut().find(|table| table.path.is_empty()) else {
+            return;
+        };
+        for entry in &mut root.values {
+            if entry.key == key && entry.origin == Origin::Default {
+                entry.value = Value::String(value.to_string());
+                entry.origin = origin;
+            }
+        }
+    }
+}
+
+/// Config file params with their origin.
+struct Source {
+    params: CommitParams,
+    origin: Origin,
+}
+
+/// Opens the configuration of the directory and resolves it
+/// the same way as [`open_settings`](crate::open_settings) does.
+pub fn open_effective_config(dir_path: &Path) -> Result<EffectiveConfig> {
+    let mut files = Vec::new();
+    let mut sources = Vec::new();
+
+    if let Ok(config_path) = CommitParams::find_config_path(dir_path) {
+        let config_files = CommitParams::open_files(&config_path)?;
+        let is_extended = config_files.len() > 1;
+        for (index, (path, params)) in config_files.into_iter().enumerate() {
+            let origin =
+                if is_extended && index == 0 { Origin::Parent } else { Origin::Child };
+            files.push((path, origin));
+            sources.push(Source { params, origin });
+        }
+    }
+
+    let mut tables = vec![root_table(&sources)];
+    tables.extend(lint_tables(&sources));
+    tables.push(ignore_table(&sources));
+    tables.extend(footer_tables(&sources));
+
+    Ok(EffectiveConfig { files, tables })
+}
+
+/// Returns the last source that sets the config field, as the child replaces the parent.
+fn replaced<'a, T>(
+    sources: &'a [Source],
+    field: impl Fn(&'a CommitConfiguration) -> Option<&'a T>,
+) -> Option<(&'a T, Origin)> {
+    sources
+        .iter()
+        .rev()
+        .find_map(|source| Some((field(&source.params.config)?, source.origin)))
+}
+
+fn root_table(sources: &[Source]) -> OriginTable {
+    let mut table = OriginTable::new(&[]);
+
+    let separators = replaced(sources, |config| config.footer_separators.as_ref());
+    let (separators, origin) = separators
+        .map_or((Footer::DEFAULT_SEPARATOR, Origin::Default), |(value, origin)| {
+            (value.as_str(), origin)
+        });
+    table.push("footer-separators", Value::from(separators), origin);
+
+    let comment_symbol = replaced(sources, |config| config.comment_symbol.as_ref());
+    let (comment_symbol, origin) = comment_symbol
+        .map_or((Message::DEFAULT_COMMENT_SYMBOL, Origin::Default), |(value, origin)| {
+            (value.as_str(), origin)
+        });
+    table.push("comment-symbol", Value::from(comment_symbol), origin);
+
+    if let Some((key, origin)) = replaced(sources, |config| config.breaking_key.as_ref()) {
+        table.push("breaking-key", Value::from(key.as_str()), origin);
+    }
+
+    table
+}
+
+fn lint_tables(sources: &[Source]) -> Vec<OriginTable> {
+    let mut lint = OriginTable::new(&["lint"]);
+    let unsafe_fixes = replaced(sources, |config| config.lint.as_ref())
+        .and_then(|(lint, origin)| Some((lint.unsafe_fixes?, origin)));
+    let (unsafe_fixes, origin) = unsafe_fixes.unwrap_or((false, Origin::Default));
+    lint.push("unsafe-fixes", Value::Boolean(unsafe_fixes), origin);
+
+    let mut tables = vec![lint];
+    let lint_value = |key: &str| {
+        sources.iter().rev().find_map(|source| {
+            let table = source.params.lint_values.get(key)?.as_table()?;
+            Some((table, source.origin))
+        })
+    };
+
+    for linter in LinterGroup::iter() {
+        let mut group = OriginTable::new(&["lint", linter.as_display()]);
+        let mut configured = RuleSet::empty();
+        if let Some((group_table, origin)) = lint_value(linter.as_display()) {
+            for (key, value) in group_table {
+                if let Some(rule) = Rule::from_name(linter, key) {
+                    configured.insert(rule);
+                }
+                group.push(key, value.clone(), origin);
+            }
+        }
+        for rule in &RuleSet::default().subtract(configured) {
+            if rule.group() == linter {
+                group.push(rule.as_display(), Value::Boolean(true), Origin::Default);
+            }
+        }
+        tables.push(group);
+    }
+
+    if let Some((overrides, origin)) = lint_value(OVERRIDES_KEY) {
+        for (kind, override_value) in overrides {
+            tables.extend(override_tables(kind, override_value, origin));
+        }
+    }
+
+    tables
+}
+
+/// Returns the tables of the override of the commit type.
+fn override_tables(kind: &str, value: &Value, origin: Origin) -> Vec<OriginTable> {
+    let mut table = OriginTable::new(&["lint", OVERRIDES_KEY, kind]);
+    let mut group_tables = Vec::new();
+
+    for (key, value) in value.as_table().into_iter().flatten() {
+        match value.as_table() {
+            Some(group_table)
+                if LinterGroup::iter().any(|group| group.as_display() == key) =>
+            {
+                let mut group = OriginTable::new(&["lint", OVERRIDES_KEY, kind, key]);
+                for (rule, rule_value) in group_table {
+                    group.push(rule, rule_value.clone(), origin);
+                }
+                group_tables.push(group);
+            }
+            _ => table.push(key, value.clone(), origin),
+        }
+    }
+
+    let mut tables = vec![table];
+    tables.extend(group_tables);
+    tables
+}
+
+fn ignore_table(sources: &[Source]) -> OriginTable {
+    let mut table = OriginTable::new(&["ignore"]);
+    let default_ignore = IgnoreConfiguration::default();
+    let (ignore, origin) = replaced(sources, |config| config.ignore.as_ref())
+        .unwrap_or((&default_ignore, Origin::Default));
+    let origin_of = |is_set: bool| if is_set { origin } else { Origin::Default };
+
+    let default_patterns = DEFAULT_IGNORE_PATTERNS.iter().map(ToString::to_string).collect();
+    let patterns = ignore.patterns.clone().unwrap_or(default_patterns);
+    table.push("patterns", Value::from(patterns), origin_of(ignore.patterns.is_some()));
+
+    let authors = ignore.authors.clone().unwrap_or_default();
+    table.push("authors", Value::from(authors), origin_of(ignore.authors.is_some()));
+
+    let merges = ignore.merges.unwrap_or(true);
+    table.push("merges", Value::Boolean(merges), origin_of(ignore.merges.is_some()));
+
+    table
+}
+
+/// Returns the tables of additional footers, the parent ones go first.
+fn footer_tables(sources: &[Source]) -> Vec<OriginTable> {
+    let mut tables = Vec::new();
+    for source in sources {
+        for footer in source.params.config.additional_footers.iter().flatten() {
+            let mut table = OriginTable::new(&["additional-footers"]);
+            table.is_array_item = true;
+            for (key, value) in footer_values(footer) {
+                table.push(&key, value, source.origin);
+            }
+            tables.push(table);
+        }
+    }
+    tables
+}
+
+/// Returns the fields of the footer that are set in the config.
+fn footer_values(footer: &AdditionalFooterConfig) -> Table {
+    match Value::try_from(footer) {
+        Ok(Value::Table(table)) => table,
+        _ => Table::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::CommitSettings;
+
+    use super::*;
+
+    fn value<'a>(config: &'a EffectiveConfig, path: &[&str], key: &str) -> &'a OriginValue {
+        config
+            .tables
+            .iter()
+            .filter(|table| table.path == path)
+            .flat_map(|table| &table.values)
+            .find(|value| value.key == key)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_effective_config_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = open_effective_config(dir.path()).unwrap();
+        assert!(config.files.is_empty());
+
+        let separators = value(&config, &[], "footer-separators");
+        assert_eq!(separators.value, Value::from(":"));
+        assert_eq!(separators.origin, Origin::Default);
+
+        let full_stop = value(&config, &["lint", "header"], "description-full-stop");
+        assert_eq!(full_stop.value, Value::Boolean(true));
+        assert_eq!(full_stop.origin, Origin::Default);
+
+        let merges = value(&config, &["ignore"], "merges");
+        assert_eq!(merges.value, Value::Boolean(true));
+    }
+
+    /// Parent config of the `extends` fixture
+    const BASE_CONFIG: &str = "comment-symbol = \";\"
+
+[lint]
+unsafe-fixes = true
+
+[lint.header]
+type-enum = [\"feat\"]
+
+[lint.body]
+max-line-length = 72
+
+[[additional-footers]]
+key = \"Base\"
+value = \"1\"
+";
+
+    /// Child config of the `extends` fixture
+    const CHILD_CONFIG: &str = "extends = \"base.toml\"
+
+[lint.header]
+description-full-stop = false
+scope-required = true
+
+[lint.overrides.chore]
+skip = true
+
+[lint.overrides.fix.footer]
+exists = [\"Refs\"]
+
+[[additional-footers]]
+key = \"Child\"
+value = \"2\"
+";
+
+    /// Config files of the fixtures shared by the effective config and settings tests
+    const FIXTURES: &[&[(&str, &str)]] = &[
+        &[],
+        &[("base.toml", BASE_CONFIG), ("commitfmt.toml", CHILD_CONFIG)],
+        &[
+            ("base.toml", BASE_CONFIG),
+            (
+                ".commitfmt.toml",
+                "extends = \"base.toml\"
+footer-separators = \":#\"
+breaking-key = \"BREAKING-CHANGE\"
+
+[lint]
+unsafe-fixes = true
+
+[lint.body]
+full-stop = { level = \"warn\" }
+
+[ignore]
+patterns = [\"^WIP\"]
+merges = false
+",
+            ),
+        ],
+        &[(
+            "commitfmt.toml",
+            "[lint.header]
+type-enum = { level = \"off\", value = [\"feat\"] }
+scope-enum = { feat = [\"api\"] }
+
+[lint.overrides.docs.header]
+max-length = 100
+
+[ignore]
+authors = [\"^dependabot\"]
+",
+        )],
+    ];
+
+    fn write_fixture(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, content) in files {
+            fs::write(dir.path().join(name), content).unwrap();
+        }
+        dir
+    }
+
+    /// Collects the configured values of the effective config into a single config table.
+    fn configured_table(config: &EffectiveConfig) -> Table {
+        let mut root = Table::new();
+        for table in &config.tables {
+            let values: Table = table
+                .values
+                .iter()
+                .filter(|value| value.origin != Origin::Default)
+                .map(|value| (value.key.clone(), value.value.clone()))
+                .collect();
+            let Some((key, parents)) = table.path.split_last() else {
+                root.extend(values);
+                continue;
+            };
+
+            let mut target = &mut root;
+            for parent in parents {
+                target = target
+                    .entry(parent.as_str())
+                    .or_insert_with(|| Value::Table(Table::new()))
+                    .as_table_mut()
+                    .unwrap();
+            }
+            let entry = target.entry(key.as_str());
+            if table.is_array_item {
+                let items = entry.or_insert_with(|| Value::Array(Vec::new()));
+                items.as_array_mut().unwrap().push(Value::Table(values));
+            } else if !values.is_empty() {
+                let group = entry.or_insert_with(|| Value::Table(Table::new()));
+                group.as_table_mut().unwrap().extend(values);
+            }
+        }
+        root
+    }
+
+    #[test]
+    fn test_effective_config_extends() {
+        let dir = write_fixture(FIXTURES[1]);
+        let config = open_effective_config(dir.path()).unwrap();
+        assert_eq!(config.files.len(), 2);
+        assert_eq!(config.files[0].1, Origin::Parent);
+
+        assert_eq!(value(&config, &[], "comment-symbol").origin, Origin::Parent);
+        // The child lint table replaces the parent one
+        assert_eq!(value(&config, &["lint"], "unsafe-fixes").origin, Origin::Default);
+        assert_eq!(
+            value(&config, &["lint", "header"], "description-full-stop").origin,
+            Origin::Child
+        );
+        assert_eq!(
+            value(&config, &["lint", "body"], "max-line-length").origin,
+            Origin::Parent
+        );
+        assert!(!config
+            .tables
+            .iter()
+            .flat_map(|table| &table.values)
+            .any(|value| { value.key == "type-enum" }));
+        assert_eq!(
+            value(&config, &["lint", "overrides", "chore"], "skip").origin,
+            Origin::Child
+        );
+        assert_eq!(
+            value(&config, &["lint", "overrides", "fix", "footer"], "exists").value,
+            Value::from(vec!["Refs"])
+        );
+
+        let footers: Vec<_> =
+            config.tables.iter().filter(|table| table.is_array_item).collect();
+        assert_eq!(footers.len(), 2);
+        assert_eq!(footers[0].values[0].origin, Origin::Parent);
+        assert_eq!(footers[1].values[0].origin, Origin::Child);
+    }
+
+    #[test]
+    fn test_apply_fallback() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("commitfmt.toml"), "footer-separators = \":#\"\n").unwrap();
+        let mut config = open_effective_config(dir.path()).unwrap();
+
+        config.apply_fallback("comment-symbol", ";", Origin::GitConfig);
+        config.apply_fallback("footer-separators", "=", Origin::GitConfig);
+
+        let comment_symbol = value(&config, &[], "comment-symbol");
+        assert_eq!(comment_symbol.value, Value::from(";"));
+        assert_eq!(comment_symbol.origin, Origin::GitConfig);
+        let separators = value(&config, &[], "footer-separators");
+        assert_eq!(separators.value, Value::from(":#"));
+        assert_eq!(separators.origin, Origin::Child);
+    }
+
+    #[test]
+    fn test_effective_config_matches_settings() {
+        for files in FIXTURES {
+            let dir = write_fixture(files);
+            let config = open_effective_config(dir.path()).unwrap();
+            let data = toml::to_string(&configured_table(&config)).unwrap();
+
+            let settings = crate::open_settings(dir.path()).unwrap();
+            assert_eq!(CommitSettings::from_toml(&data).unwrap(), settings, "{data}");
+        }
+    }
+}
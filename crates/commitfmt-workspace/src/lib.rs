@@ -1,13 +1,20 @@
+mod check;
 mod configuration;
+mod effective;
 mod rules;
 mod settings;
 
+pub use check::{check_config, ConfigCheck, ConfigProblem, Location};
+pub use effective::{
+    open_effective_config, EffectiveConfig, Origin, OriginTable, OriginValue,
+};
+pub use rules::rule_setting_type;
 pub use settings::AdditionalFooter;
 
 use commitfmt_linter::rules::LinterGroup;
 use thiserror::Error;
 
-pub use settings::{open_settings, CommitSettings, OnConflictAction};
+pub use settings::{open_settings, CommitSettings, IgnoreSettings, OnConflictAction};
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -71,6 +78,12 @@ pub enum Error {
     #[error("Invalid text case: {0}")]
     InvalidTextCase(String),
 
+    #[error("Invalid rule level: {0}. Expected \"error\", \"warn\" or \"off\"")]
+    InvalidRuleLevel(String),
+
+    #[error("Invalid breaking change key: {0}")]
+    InvalidBreakingKey(String),
+
     #[error("Invalid pattern: {0}")]
     InvalidPattern(#[from] regex_lite::Error),
 
@@ -1,11 +1,84 @@
 use commitfmt_linter::{
     case::{IdentifierCase, TextCase},
-    rules::{Rule, Settings},
+    rules::{
+        footer::{CoAuthorCheck, KeyPattern, SignOffCheck},
+        header::{AllowedScopes, ScopeRequirement},
+        LinterGroup, Rule, Settings,
+    },
 };
+use std::borrow::Cow;
+
+use regex_lite::Regex;
 use toml::Value;
 
 use crate::{Error, Result};
 
+/// Key of the rule table with the severity level
+const LEVEL_KEY: &str = "level";
+/// Key of the rule table with the rule value
+const VALUE_KEY: &str = "value";
+
+/// Severity level of a rule in the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RuleLevel {
+    Error,
+    Warn,
+    Off,
+}
+
+impl RuleLevel {
+    fn from_name(name: &str) -> Option<RuleLevel> {
+        match name {
+            "error" => Some(RuleLevel::Error),
+            "warn" => Some(RuleLevel::Warn),
+            "off" => Some(RuleLevel::Off),
+            _ => None,
+        }
+    }
+}
+
+/// Splits the `{ level = "warn", ... }` table into the level and the rule value.
+///
+/// A table is a level wrapper only if its `level` names a level, so rule values
+/// can have the `level` key too. The rule value is taken from the `value` key
+/// or from the rest of the table. It's `None` if the table has the level only.
+pub(crate) fn parse_rule_level(value: &Value) -> Result<(RuleLevel, Option<Cow<'_, Value>>)> {
+    let Some(level) = value
+        .as_table()
+        .and_then(|table| table.get(LEVEL_KEY))
+        .and_then(Value::as_str)
+        .and_then(RuleLevel::from_name)
+    else {
+        return Ok((RuleLevel::Error, Some(Cow::Borrowed(value))));
+    };
+
+    let mut table = value.as_table().cloned().unwrap_or_default();
+    table.remove(LEVEL_KEY);
+    let rule_value = match table.remove(VALUE_KEY) {
+        Some(rule_value) if table.is_empty() => Some(rule_value),
+        Some(_) => {
+            return Err(Error::UnexpectedFieldType(
+                VALUE_KEY.to_string(),
+                "the only rule value".to_string(),
+            ))
+        }
+        None if table.is_empty() => None,
+        None => Some(Value::Table(table)),
+    };
+
+    Ok((level, rule_value.map(Cow::Owned)))
+}
+
+/// Reports the invalid `level` of the table if the table isn't a valid rule value either.
+fn rule_value_error(value: &Value, err: Error) -> Error {
+    match value.as_table().and_then(|table| table.get(LEVEL_KEY)) {
+        Some(level) if level.as_str().and_then(RuleLevel::from_name).is_none() => {
+            Error::InvalidRuleLevel(level.to_string())
+        }
+        _ => err,
+    }
+}
+
 /// Parse a rule setting from a TOML value
 ///
 /// Returns `true` if the setting is enabled, `false` if the setting is disabled.
@@ -24,7 +97,10 @@ pub(crate) fn parse_rule_setting(
             reader.usize(&mut settings.header.description_min_length)
         }
         Rule::HeaderDescriptionCase => reader.text_case(&mut settings.header.description_case),
-        Rule::HeaderScopeEnum => reader.str_vec(&mut settings.header.scope_enum),
+        Rule::HeaderScopeEnum => reader.allowed_scopes(&mut settings.header.scope_enum),
+        Rule::HeaderScopeRequired => {
+            reader.scope_requirement(&mut settings.header.scope_required)
+        }
         Rule::HeaderScopeCase => reader.id_case(&mut settings.header.scope_case),
         Rule::HeaderMaxLength => reader.usize(&mut settings.header.max_length),
         Rule::HeaderMinLength => reader.usize(&mut settings.header.min_length),
@@ -46,8 +122,25 @@ pub(crate) fn parse_rule_setting(
         Rule::FooterMaxLineLength => reader.usize(&mut settings.footer.max_line_length),
         Rule::FooterKeyCase => reader.id_case(&mut settings.footer.key_case),
         Rule::FooterExists => reader.str_vec(&mut settings.footer.required),
-
-        _ => match value.as_bool() {
+        Rule::FooterKeyEnum => {
+            reader.key_enum(&mut settings.footer.key_enum, &mut settings.footer.key_aliases)
+        }
+        Rule::FooterValuePattern => reader.key_patterns(&mut settings.footer.value_pattern),
+        Rule::FooterSignedOffBy => reader.bool_or_keyword(
+            &mut settings.footer.sign_off,
+            SignOffCheck::Format,
+            ("author", SignOffCheck::Author),
+        ),
+        Rule::FooterCoAuthoredBy => reader.bool_or_keyword(
+            &mut settings.footer.co_author,
+            CoAuthorCheck::Identity,
+            ("canonical", CoAuthorCheck::Canonical),
+        ),
+
+        Rule::HeaderDescriptionFullStop
+        | Rule::HeaderTypeRequired
+        | Rule::BodyFullStop
+        | Rule::FooterBreakingExclamation => match value.as_bool() {
             Some(is_enabled) => Ok(is_enabled),
             None => Err(Error::UnexpectedFieldType(
                 rule.as_display().to_owned(),
@@ -57,6 +150,75 @@ pub(crate) fn parse_rule_setting(
     }
 }
 
+/// Parses the rule entry of a linter group table into the settings.
+///
+/// Returns the rule and its level. The level is `Off` if the rule is disabled by its value.
+pub(crate) fn parse_rule(
+    settings: &mut Settings,
+    linter: LinterGroup,
+    key: &str,
+    value: &Value,
+) -> Result<(Rule, RuleLevel)> {
+    let Some(rule) = Rule::from_name(linter, key) else {
+        return Err(Error::UnknownRule(linter, key.to_owned()));
+    };
+
+    let (level, rule_value) = parse_rule_level(value)?;
+    // A table with the level only enables the rule.
+    // The value of a disabled rule is validated, but not applied.
+    let is_enabled = match (level, rule_value) {
+        (RuleLevel::Off, None) => false,
+        (RuleLevel::Off, Some(rule_value)) => {
+            parse_rule_setting(rule, &mut settings.clone(), &rule_value)?;
+            false
+        }
+        (_, rule_value) => {
+            let rule_value = rule_value.unwrap_or(Cow::Owned(Value::Boolean(true)));
+            parse_rule_setting(rule, settings, &rule_value)
+                .map_err(|err| rule_value_error(value, err))?
+        }
+    };
+
+    if level == RuleLevel::Off || !is_enabled {
+        return Ok((rule, RuleLevel::Off));
+    }
+    Ok((rule, level))
+}
+
+/// Returns the type of the rule setting value in the config.
+pub fn rule_setting_type(rule: Rule) -> &'static str {
+    match rule {
+        Rule::HeaderDescriptionMaxLength
+        | Rule::HeaderDescriptionMinLength
+        | Rule::HeaderMaxLength
+        | Rule::HeaderMinLength
+        | Rule::HeaderScopeMaxLength
+        | Rule::HeaderScopeMinLength
+        | Rule::HeaderTypeMaxLength
+        | Rule::HeaderTypeMinLength
+        | Rule::BodyMaxLineLength
+        | Rule::BodyMaxLength
+        | Rule::BodyMinLength
+        | Rule::FooterMaxLength
+        | Rule::FooterMinLength
+        | Rule::FooterMaxLineLength => "integer",
+        Rule::HeaderDescriptionCase | Rule::BodyCase => "text case",
+        Rule::HeaderScopeCase | Rule::HeaderTypeCase | Rule::FooterKeyCase => {
+            "identifier case"
+        }
+        Rule::HeaderTypeEnum | Rule::FooterExists => "array",
+        Rule::HeaderScopeEnum | Rule::FooterKeyEnum => "array or table",
+        Rule::HeaderScopeRequired => "bool or table",
+        Rule::FooterValuePattern => "table",
+        Rule::FooterSignedOffBy => "bool or \"author\"",
+        Rule::FooterCoAuthoredBy => "bool or \"canonical\"",
+        Rule::HeaderDescriptionFullStop
+        | Rule::HeaderTypeRequired
+        | Rule::BodyFullStop
+        | Rule::FooterBreakingExclamation => "bool",
+    }
+}
+
 struct RuleSettingsReader<'a> {
     rule: Rule,
     value: &'a Value,
@@ -99,6 +261,31 @@ impl<'a> RuleSettingsReader<'a> {
         Ok(true)
     }
 
+    /// Reads a rule that is enabled with `true` or with the keyword
+    /// that turns on the stricter check.
+    fn bool_or_keyword<T>(
+        &self,
+        target: &mut T,
+        enabled: T,
+        keyword: (&str, T),
+    ) -> Result<bool> {
+        let (name, strict) = keyword;
+        match self.value {
+            Value::Boolean(is_enabled) => {
+                *target = enabled;
+                Ok(*is_enabled)
+            }
+            Value::String(value) if value == name => {
+                *target = strict;
+                Ok(true)
+            }
+            _ => Err(Error::UnexpectedFieldType(
+                self.rule.as_display().to_string(),
+                format!("bool or \"{name}\""),
+            )),
+        }
+    }
+
     fn usize(&self, target: &mut usize) -> Result<bool> {
         let Some(parsed) = self.value.as_integer() else {
             return Err(Error::UnexpectedFieldType(
@@ -120,6 +307,109 @@ impl<'a> RuleSettingsReader<'a> {
         Ok(true)
     }
 
+    /// Reads the list of scopes or the table of scope lists keyed by type.
+    fn allowed_scopes(&self, target: &mut AllowedScopes) -> Result<bool> {
+        let Some(table) = self.value.as_table() else {
+            let mut scopes = Vec::new();
+            let is_enabled = self.str_vec(&mut scopes)?;
+            *target = AllowedScopes::All(scopes);
+            return Ok(is_enabled);
+        };
+
+        let mut types = Vec::new();
+        for (kind, value) in table {
+            let mut scopes = Vec::new();
+            RuleSettingsReader::new(self.rule, value).str_vec(&mut scopes)?;
+            types.push((Box::from(kind.as_str()), scopes));
+        }
+
+        *target = AllowedScopes::ByType(types);
+        Ok(true)
+    }
+
+    /// Reads the flag or the table of flags keyed by type.
+    fn scope_requirement(&self, target: &mut ScopeRequirement) -> Result<bool> {
+        if let Some(is_enabled) = self.value.as_bool() {
+            *target = ScopeRequirement::All;
+            return Ok(is_enabled);
+        }
+        let Some(table) = self.value.as_table() else {
+            return Err(Error::UnexpectedFieldType(
+                self.rule.as_display().to_string(),
+                "bool or table".to_string(),
+            ));
+        };
+
+        let mut types = Vec::new();
+        for (kind, value) in table {
+            let Some(is_required) = value.as_bool() else {
+                return Err(Error::UnexpectedValueType("bool".to_string()));
+            };
+            if is_required {
+                types.push(Box::from(kind.as_str()));
+            }
+        }
+
+        let is_enabled = !types.is_empty();
+        *target = ScopeRequirement::ByType(types);
+        Ok(is_enabled)
+    }
+
+    /// Reads the list of keys or the table with `keys` and `aliases`.
+    fn key_enum(
+        &self,
+        keys: &mut Vec<Box<str>>,
+        aliases: &mut Vec<(Box<str>, Box<str>)>,
+    ) -> Result<bool> {
+        let Some(table) = self.value.as_table() else {
+            return self.str_vec(keys);
+        };
+
+        let mut is_enabled = false;
+        if let Some(keys_value) = table.get("keys") {
+            is_enabled = RuleSettingsReader::new(self.rule, keys_value).str_vec(keys)?;
+        }
+        if let Some(aliases_value) = table.get("aliases") {
+            let Some(aliases_table) = aliases_value.as_table() else {
+                return Err(Error::UnexpectedValueType("table".to_string()));
+            };
+            let mut result = Vec::new();
+            for (alias, key) in aliases_table {
+                let Some(key) = key.as_str() else {
+                    return Err(Error::UnexpectedValueType("string".to_string()));
+                };
+                result.push((Box::from(alias.as_str()), Box::from(key)));
+            }
+            *aliases = result;
+            is_enabled = true;
+        }
+
+        Ok(is_enabled)
+    }
+
+    fn key_patterns(&self, target: &mut Vec<KeyPattern>) -> Result<bool> {
+        let Some(table) = self.value.as_table() else {
+            return Err(Error::UnexpectedFieldType(
+                self.rule.as_display().to_string(),
+                "table".to_string(),
+            ));
+        };
+
+        let mut result = Vec::new();
+        for (key, value) in table {
+            let Some(pattern) = value.as_str() else {
+                return Err(Error::UnexpectedValueType("string".to_string()));
+            };
+            result.push(KeyPattern {
+                key: Box::from(key.as_str()),
+                pattern: Regex::new(pattern)?,
+            });
+        }
+
+        *target = result;
+        Ok(!target.is_empty())
+    }
+
     fn str_vec(&self, target: &mut Vec<Box<str>>) -> Result<bool> {
         let Some(parsed) = self.value.as_array() else {
             return Err(Error::UnexpectedFieldType(
@@ -146,11 +436,17 @@ impl<'a> RuleSettingsReader<'a> {
 mod tests {
     use commitfmt_linter::{
         case::{IdentifierCase, TextCase},
-        rules::Rule,
+        rules::{
+            footer::{CoAuthorCheck, SignOffCheck},
+            header::{AllowedScopes, ScopeRequirement},
+            Rule, Settings,
+        },
     };
 
     use crate::{CommitSettings, Error};
 
+    use super::{parse_rule_level, parse_rule_setting, rule_setting_type, RuleLevel};
+
     #[test]
     fn test_parse_all_rule_setting_types() {
         let settings = CommitSettings::from_toml(
@@ -187,6 +483,10 @@ key-case = "capitalized-kebab"
 max-length = 200
 max-line-length = 120
 min-length = 3
+signed-off-by = "author"
+co-authored-by = "canonical"
+key-enum = { keys = ["Issue-ID", "Refs"], aliases = { Jira = "Issue-ID" } }
+value-pattern = { "Issue-ID" = "^[A-Z]+-[0-9]+$", "Refs" = "^#\\d+$" }
 "#,
         )
         .unwrap();
@@ -200,8 +500,9 @@ min-length = 3
         assert_eq!(rules.settings.header.scope_case, IdentifierCase::Kebab);
         assert_eq!(
             rules.settings.header.scope_enum,
-            [Box::<str>::from("api"), Box::<str>::from("core")]
+            AllowedScopes::All(vec![Box::from("api"), Box::from("core")])
         );
+        assert_eq!(rules.settings.header.scope_required, ScopeRequirement::All);
         assert_eq!(rules.settings.header.scope_max_length, 12);
         assert_eq!(rules.settings.header.scope_min_length, 2);
         assert_eq!(rules.settings.header.type_case, IdentifierCase::Lower);
@@ -222,6 +523,24 @@ min-length = 3
         assert_eq!(rules.settings.footer.max_length, 200);
         assert_eq!(rules.settings.footer.max_line_length, 120);
         assert_eq!(rules.settings.footer.min_length, 3);
+        assert_eq!(rules.settings.footer.sign_off, SignOffCheck::Author);
+        assert_eq!(rules.settings.footer.co_author, CoAuthorCheck::Canonical);
+        assert_eq!(
+            rules.settings.footer.key_enum,
+            [Box::<str>::from("Issue-ID"), Box::<str>::from("Refs")]
+        );
+        assert_eq!(
+            rules.settings.footer.key_aliases,
+            [(Box::<str>::from("Jira"), Box::<str>::from("Issue-ID"))]
+        );
+        let patterns: Vec<_> = rules
+            .settings
+            .footer
+            .value_pattern
+            .iter()
+            .map(|rule| (&*rule.key, rule.pattern.as_str()))
+            .collect();
+        assert_eq!(patterns, [("Issue-ID", "^[A-Z]+-[0-9]+$"), ("Refs", r"^#\d+$")]);
 
         assert!(rules.set.contains(Rule::HeaderScopeRequired));
         assert!(rules.set.contains(Rule::HeaderTypeRequired));
@@ -230,6 +549,141 @@ min-length = 3
         assert!(!rules.set.contains(Rule::FooterBreakingExclamation));
     }
 
+    #[test]
+    fn test_rule_setting_type() {
+        assert_eq!(rule_setting_type(Rule::HeaderTypeRequired), "bool");
+        assert_eq!(rule_setting_type(Rule::BodyMaxLineLength), "integer");
+        assert_eq!(rule_setting_type(Rule::FooterKeyCase), "identifier case");
+        assert_eq!(rule_setting_type(Rule::HeaderScopeEnum), "array or table");
+        assert_eq!(rule_setting_type(Rule::FooterSignedOffBy), "bool or \"author\"");
+    }
+
+    #[test]
+    fn test_rule_setting_type_matches_parser() {
+        for rule in Rule::all() {
+            let value = match rule_setting_type(rule) {
+                "integer" => toml::Value::Integer(72),
+                "text case" => toml::Value::from("upper-first"),
+                "identifier case" => toml::Value::from("kebab"),
+                "array" | "array or table" => toml::Value::from(vec!["feat"]),
+                "table" => toml::from_str::<toml::Value>("Refs = '^#[0-9]+$'").unwrap(),
+                _ => toml::Value::Boolean(true),
+            };
+            let result = parse_rule_setting(rule, &mut Settings::default(), &value);
+            assert!(matches!(result, Ok(true)), "{}: {result:?}", rule.id());
+        }
+    }
+
+    #[test]
+    fn test_parse_rule_level() {
+        let value: toml::Table = toml::from_str(
+            r#"
+plain = 72
+level-only = { level = "warn" }
+with-value = { level = "off", value = ["feat"] }
+with-table = { level = "error", keys = ["Refs"] }
+invalid = { level = "info" }
+"#,
+        )
+        .unwrap();
+
+        let (level, rule_value) = parse_rule_level(&value["plain"]).unwrap();
+        assert_eq!(level, RuleLevel::Error);
+        assert_eq!(rule_value.unwrap().as_integer(), Some(72));
+        let (level, rule_value) = parse_rule_level(&value["level-only"]).unwrap();
+        assert_eq!(level, RuleLevel::Warn);
+        assert!(rule_value.is_none());
+        let (level, rule_value) = parse_rule_level(&value["with-value"]).unwrap();
+        assert_eq!(level, RuleLevel::Off);
+        assert!(rule_value.unwrap().is_array());
+        let (level, rule_value) = parse_rule_level(&value["with-table"]).unwrap();
+        assert_eq!(level, RuleLevel::Error);
+        assert!(rule_value
+            .unwrap()
+            .as_table()
+            .is_some_and(|table| table.contains_key("keys")));
+        let (level, rule_value) = parse_rule_level(&value["invalid"]).unwrap();
+        assert_eq!(level, RuleLevel::Error);
+        assert_eq!(rule_value.unwrap().as_ref(), &value["invalid"]);
+    }
+
+    #[test]
+    fn test_parse_rule_values_with_level_key() {
+        let settings = CommitSettings::from_toml(
+            r#"
+[lint.header]
+scope-enum = { level = ["api"], docs = ["readme"] }
+
+[lint.footer]
+value-pattern = { level = "^x$" }
+"#,
+        )
+        .unwrap();
+        let rules = &settings.rules.settings;
+
+        assert_eq!(
+            rules.header.scope_enum,
+            AllowedScopes::ByType(vec![
+                ("docs".into(), vec!["readme".into()]),
+                ("level".into(), vec!["api".into()]),
+            ])
+        );
+        assert_eq!(rules.footer.value_pattern.len(), 1);
+        assert_eq!(rules.footer.value_pattern[0].key.as_ref(), "level");
+        assert_eq!(rules.footer.value_pattern[0].pattern.as_str(), "^x$");
+        assert!(settings.rules.set.contains(Rule::HeaderScopeEnum));
+        assert!(settings.rules.set.contains(Rule::FooterValuePattern));
+    }
+
+    #[test]
+    fn test_parse_disabled_rule_value() {
+        let settings = CommitSettings::from_toml(
+            r#"
+[lint.header]
+type-enum = { level = "off", value = ["feat"] }
+max-length = { level = "off" }
+"#,
+        )
+        .unwrap();
+        assert!(settings.rules.settings.header.type_enum.is_empty());
+        assert!(!settings.rules.set.contains(Rule::HeaderTypeEnum));
+
+        let invalid = CommitSettings::from_toml(
+            r#"
+[lint.header]
+type-enum = { level = "off", value = "feat" }
+"#,
+        );
+        assert!(matches!(invalid, Err(Error::UnexpectedFieldType(..))));
+    }
+
+    #[test]
+    fn test_parse_scope_settings_by_type() {
+        let settings = CommitSettings::from_toml(
+            r#"
+[lint.header]
+scope-enum = { feat = ["api", "web"], ci = ["gha", "docker"] }
+scope-required = { feat = true, docs = false }
+"#,
+        )
+        .unwrap();
+        let header = &settings.rules.settings.header;
+
+        assert_eq!(
+            header.scope_enum,
+            AllowedScopes::ByType(vec![
+                (Box::from("ci"), vec![Box::from("gha"), Box::from("docker")]),
+                (Box::from("feat"), vec![Box::from("api"), Box::from("web")]),
+            ])
+        );
+        assert_eq!(header.scope_required, ScopeRequirement::ByType(vec![Box::from("feat")]));
+        assert!(settings.rules.set.contains(Rule::HeaderScopeRequired));
+
+        let invalid =
+            CommitSettings::from_toml("[lint.header]\nscope-required = { feat = 1 }");
+        assert!(matches!(invalid, Err(Error::UnexpectedValueType(_))));
+    }
+
     #[test]
     fn test_reject_invalid_rule_setting_values() {
         let wrong_bool = CommitSettings::from_toml("[lint.header]\ntype-required = 1");
@@ -251,5 +705,24 @@ min-length = 3
         let invalid_array_item =
             CommitSettings::from_toml("[lint.footer]\nexists = [\"Issue-ID\", 42]");
         assert!(matches!(invalid_array_item, Err(Error::UnexpectedValueType(_))));
+
+        let invalid_sign_off =
+            CommitSettings::from_toml("[lint.footer]\nsigned-off-by = \"committer\"");
+        assert!(matches!(invalid_sign_off, Err(Error::UnexpectedFieldType(_, _))));
+
+        let list_key_enum =
+            CommitSettings::from_toml("[lint.footer]\nkey-enum = [\"Issue-ID\"]").unwrap();
+        assert_eq!(
+            list_key_enum.rules.settings.footer.key_enum,
+            [Box::<str>::from("Issue-ID")]
+        );
+
+        let invalid_alias =
+            CommitSettings::from_toml("[lint.footer]\nkey-enum = { aliases = { Jira = 1 } }");
+        assert!(matches!(invalid_alias, Err(Error::UnexpectedValueType(_))));
+
+        let invalid_value_pattern =
+            CommitSettings::from_toml("[lint.footer]\nvalue-pattern = { Refs = \"(\" }");
+        assert!(matches!(invalid_value_pattern, Err(Error::InvalidPattern(_))));
     }
 }
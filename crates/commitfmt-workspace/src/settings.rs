@@ -1,14 +1,15 @@
 use std::{cell::RefCell, path::Path};
 
 use regex_lite::Regex;
-use toml::Table;
+use toml::{Table, Value};
 
 use commitfmt_cc::footer::SeparatorAlignment;
+use commitfmt_cc::Footer;
 use commitfmt_linter::{rules, Rule, RuleSet};
 use commitfmt_tpl::Template;
 
-use crate::configuration::{AdditionalFooterConfig, CommitParams};
-use crate::rules::parse_rule_setting;
+use crate::configuration::{AdditionalFooterConfig, CommitParams, IgnoreConfiguration};
+use crate::rules::{parse_rule, RuleLevel};
 use crate::{Error, Result};
 
 #[derive(Debug, PartialEq, Copy, Clone, Default)]
@@ -28,6 +29,14 @@ impl OnConflictAction {
             _ => Err(Error::UnknownOnConflictAction(s.to_string())),
         }
     }
+
+    pub fn as_display(&self) -> &'static str {
+        match self {
+            OnConflictAction::Skip => "skip",
+            OnConflictAction::Append => "append",
+            OnConflictAction::Error => "error",
+        }
+    }
 }
 
 /// Additional footer information
@@ -75,10 +84,37 @@ impl PartialEq for AdditionalFooter {
     }
 }
 
-#[derive(Debug, PartialEq, Default)]
+/// Key of the lint table with the settings for each commit type
+pub(crate) const OVERRIDES_KEY: &str = "overrides";
+/// Key of the override that disables all rules for the commit type
+pub(crate) const SKIP_KEY: &str = "skip";
+
+#[derive(Debug, PartialEq)]
 pub struct RulesSettings {
     pub set: RuleSet,
+    /// Enabled rules whose violations are reported as warnings
+    pub warnings: RuleSet,
     pub settings: rules::Settings,
+    /// Rules layered on top of the base ones for each commit type
+    pub overrides: Vec<rules::TypeOverride>,
+}
+
+impl Default for RulesSettings {
+    fn default() -> Self {
+        Self {
+            set: RuleSet::default(),
+            warnings: RuleSet::empty(),
+            settings: rules::Settings::default(),
+            overrides: Vec::new(),
+        }
+    }
+}
+
+/// Rules of a linter group table, split by their state.
+struct GroupRules {
+    enabled: RuleSet,
+    disabled: RuleSet,
+    warnings: RuleSet,
 }
 
 impl RulesSettings {
@@ -86,23 +122,26 @@ impl RulesSettings {
         &mut self,
         linter: rules::LinterGroup,
         linter_table: &Table,
-    ) -> Result<(RuleSet, RuleSet)> {
-        let mut enabled_rules = RuleSet::empty();
-        let mut disabled_rules = RuleSet::empty();
-
-        for key in linter_table.keys() {
-            let Some(rule) = Rule::from_name(linter, key) else {
-                return Err(Error::UnknownRule(linter, key.to_owned()));
-            };
+    ) -> Result<GroupRules> {
+        let mut group = GroupRules {
+            enabled: RuleSet::empty(),
+            disabled: RuleSet::empty(),
+            warnings: RuleSet::empty(),
+        };
 
-            if parse_rule_setting(rule, &mut self.settings, &linter_table[key])? {
-                enabled_rules.insert(rule);
-            } else {
-                disabled_rules.insert(rule);
+        for (key, value) in linter_table {
+            let (rule, level) = parse_rule(&mut self.settings, linter, key, value)?;
+            if level == RuleLevel::Off {
+                group.disabled.insert(rule);
+                continue;
+            }
+            group.enabled.insert(rule);
+            if level == RuleLevel::Warn {
+                group.warnings.insert(rule);
             }
         }
 
-        Ok((enabled_rules, disabled_rules))
+        Ok(group)
     }
 
     pub(crate) fn from_params(params: &CommitParams) -> Result<Self> {
@@ -112,8 +151,27 @@ impl RulesSettings {
             return Ok(settings);
         }
 
+        settings.apply_groups(&params.lint_values)?;
+
+        if let Some(overrides_value) = params.lint_values.get(OVERRIDES_KEY) {
+            let Some(overrides_table) = overrides_value.as_table() else {
+                return Err(Error::UnexpectedFieldType(
+                    OVERRIDES_KEY.to_string(),
+                    "table".to_string(),
+                ));
+            };
+            for (kind, value) in overrides_table {
+                settings.overrides.push(settings.parse_override(kind, value)?);
+            }
+        }
+
+        Ok(settings)
+    }
+
+    /// Applies the rules of linter group tables (e.g. `header`) from the lint table.
+    fn apply_groups(&mut self, lint_table: &Table) -> Result<()> {
         for linter in rules::LinterGroup::iter() {
-            let Some(table_value) = params.lint_values.get(linter.as_display()) else {
+            let Some(table_value) = lint_table.get(linter.as_display()) else {
                 continue;
             };
             let Some(linter_table) = table_value.as_table() else {
@@ -123,14 +181,65 @@ impl RulesSettings {
                 ));
             };
 
-            let (enabled_rules, disabled_rules) =
-                settings.parse_group(linter, linter_table)?;
+            let group = self.parse_group(linter, linter_table)?;
 
-            settings.set = settings.set.subtract(disabled_rules);
-            settings.set = settings.set.union(enabled_rules);
+            self.set = self.set.subtract(group.disabled);
+            self.set = self.set.union(group.enabled);
+            self.warnings = self.warnings.subtract(group.disabled.union(group.enabled));
+            self.warnings = self.warnings.union(group.warnings);
         }
 
-        Ok(settings)
+        Ok(())
+    }
+
+    /// Layers the override of the commit type on top of the base rules.
+    fn parse_override(&self, kind: &str, value: &Value) -> Result<rules::TypeOverride> {
+        let Some(override_table) = value.as_table() else {
+            return Err(Error::UnexpectedFieldType(
+                format!("{OVERRIDES_KEY}.{kind}"),
+                "table".to_string(),
+            ));
+        };
+
+        let mut layer = Self {
+            set: self.set,
+            warnings: self.warnings,
+            settings: self.settings.clone(),
+            overrides: Vec::new(),
+        };
+        layer.apply_groups(override_table)?;
+
+        match override_table.get(SKIP_KEY).map(Value::as_bool) {
+            None | Some(Some(false)) => {}
+            Some(Some(true)) => layer.set = RuleSet::empty(),
+            Some(None) => {
+                return Err(Error::UnexpectedFieldType(
+                    SKIP_KEY.to_string(),
+                    "bool".to_string(),
+                ))
+            }
+        }
+
+        Ok(rules::TypeOverride {
+            kind: Box::from(kind),
+            set: layer.set,
+            warnings: layer.warnings,
+            settings: layer.settings,
+        })
+    }
+
+    /// Reports the violations of warning rules as errors, e.g. with `--strict`.
+    pub fn deny_warnings(&mut self) {
+        self.warnings = RuleSet::empty();
+        for type_override in &mut self.overrides {
+            type_override.warnings = RuleSet::empty();
+        }
+    }
+
+    /// Checks that the rule is enabled for any commit type.
+    pub fn uses(&self, rule: Rule) -> bool {
+        self.set.contains(rule)
+            || self.overrides.iter().any(|type_override| type_override.set.contains(rule))
     }
 }
 
@@ -149,14 +258,99 @@ impl LintSettings {
     }
 }
 
+/// Message patterns of commits ignored by default
+pub(crate) const DEFAULT_IGNORE_PATTERNS: &[&str] = &["^Merge", "^Revert"];
+
+/// Parsed ignore settings
+#[derive(Debug, Clone)]
+pub struct IgnoreSettings {
+    /// Patterns matched against the whole commit message
+    pub patterns: Vec<Regex>,
+    /// Patterns matched against the author in the `Name <email>` form
+    pub authors: Vec<Regex>,
+    /// Whether commits with more than one parent are ignored
+    pub merges: bool,
+}
+
+impl Default for IgnoreSettings {
+    fn default() -> Self {
+        let patterns = DEFAULT_IGNORE_PATTERNS
+            .iter()
+            .map(|pattern| Regex::new(pattern).expect("default ignore pattern is valid"))
+            .collect();
+
+        Self { patterns, authors: Vec::new(), merges: true }
+    }
+}
+
+impl PartialEq for IgnoreSettings {
+    fn eq(&self, other: &Self) -> bool {
+        fn sources(patterns: &[Regex]) -> Vec<&str> {
+            patterns.iter().map(Regex::as_str).collect()
+        }
+
+        sources(&self.patterns) == sources(&other.patterns)
+            && sources(&self.authors) == sources(&other.authors)
+            && self.merges == other.merges
+    }
+}
+
+impl IgnoreSettings {
+    pub(crate) fn from_config(config: IgnoreConfiguration) -> Result<Self> {
+        let mut settings = Self::default();
+
+        if let Some(patterns) = config.patterns {
+            settings.patterns = compile_patterns(&patterns)?;
+        }
+        if let Some(authors) = config.authors {
+            settings.authors = compile_patterns(&authors)?;
+        }
+        if let Some(merges) = config.merges {
+            settings.merges = merges;
+        }
+
+        Ok(settings)
+    }
+
+    /// Returns true if the commit must not be checked.
+    ///
+    /// Patterns are matched against the message without the trailing whitespace,
+    /// since git ends messages with a newline.
+    /// `author` is only matched if it's known, e.g. it's not available for stdin input.
+    pub fn is_ignored(
+        &self,
+        message: &str,
+        author: Option<&str>,
+        parents_count: usize,
+    ) -> bool {
+        if self.merges && parents_count > 1 {
+            return true;
+        }
+        let message = message.trim_end();
+        if self.patterns.iter().any(|pattern| pattern.is_match(message)) {
+            return true;
+        }
+
+        author
+            .is_some_and(|author| self.authors.iter().any(|pattern| pattern.is_match(author)))
+    }
+}
+
+fn compile_patterns(patterns: &[String]) -> Result<Vec<Regex>> {
+    patterns.iter().map(|pattern| Regex::new(pattern).map_err(Error::InvalidPattern)).collect()
+}
+
 /// Parsed commit settings
 #[derive(Debug, PartialEq, Default)]
 pub struct CommitSettings {
     pub rules: RulesSettings,
     pub lint: LintSettings,
+    pub ignore: IgnoreSettings,
     pub footers: RefCell<Vec<AdditionalFooter>>,
     pub footer_separators: Option<String>,
     pub comment_symbol: Option<String>,
+    /// Spelling of the breaking change footer key written by the formatter
+    pub breaking_key: Option<String>,
 }
 
 impl CommitSettings {
@@ -168,6 +362,13 @@ impl CommitSettings {
     pub(crate) fn from_params(params: CommitParams) -> Result<Self> {
         let lint = LintSettings::from_params(&params);
         let rules = RulesSettings::from_params(&params)?;
+        let ignore = IgnoreSettings::from_config(params.config.ignore.unwrap_or_default())?;
+
+        if let Some(key) = &params.config.breaking_key {
+            if !Footer::is_breaking_key(key) {
+                return Err(Error::InvalidBreakingKey(key.clone()));
+            }
+        }
 
         let footer_configs = params.config.additional_footers.unwrap_or_default();
 
@@ -181,9 +382,11 @@ impl CommitSettings {
         Ok(Self {
             rules,
             lint,
+            ignore,
             footers: RefCell::new(footers),
             footer_separators: params.config.footer_separators,
             comment_symbol: params.config.comment_symbol,
+            breaking_key: params.config.breaking_key,
         })
     }
 }
@@ -212,6 +415,12 @@ mod tests {
         assert_eq!(OnConflictAction::from_config("append").unwrap(), OnConflictAction::Append);
         assert_eq!(OnConflictAction::from_config("error").unwrap(), OnConflictAction::Error);
         assert!(OnConflictAction::from_config("unknown").is_err());
+
+        for action in
+            [OnConflictAction::Skip, OnConflictAction::Append, OnConflictAction::Error]
+        {
+            assert_eq!(OnConflictAction::from_config(action.as_display()).unwrap(), action);
+        }
     }
 
     #[test]
@@ -274,8 +483,10 @@ mod tests {
             config: CommitConfiguration {
                 extends: None,
                 lint: Some(LintConfiguration { unsafe_fixes: Some(true) }),
+                ignore: None,
                 footer_separators: None,
                 comment_symbol: None,
+                breaking_key: None,
                 additional_footers: Some(vec![AdditionalFooterConfig {
                     key: "Footer".to_string(),
                     on_conflict: Some("error".to_string()),
@@ -292,4 +503,260 @@ mod tests {
         assert_eq!(settings.set, RuleSet::default());
         assert_eq!(settings.settings, rules::Settings::default());
     }
+
+    #[test]
+    fn test_rules_settings_overrides() {
+        let settings = CommitSettings::from_toml(
+            r#"
+[lint.header]
+type-enum = ["feat", "fix", "chore"]
+
+[lint.overrides.fix.footer]
+exists = ["Refs"]
+
+[lint.overrides.feat.body]
+min-length = 20
+
+[lint.overrides.chore]
+skip = true
+"#,
+        )
+        .unwrap();
+        let rules = &settings.rules;
+        assert!(!rules.set.contains(Rule::FooterExists));
+        assert!(rules.uses(Rule::FooterExists));
+        assert!(!rules.uses(Rule::FooterSignedOffBy));
+
+        let [chore, feat, fix] = &rules.overrides[..] else {
+            panic!("Unexpected overrides: {:?}", rules.overrides);
+        };
+        assert_eq!(&*chore.kind, "chore");
+        assert!(chore.set.is_empty());
+
+        assert_eq!(&*feat.kind, "feat");
+        assert!(feat.set.contains(Rule::BodyMinLength));
+        assert!(feat.set.contains(Rule::HeaderTypeEnum));
+        assert_eq!(feat.settings.body.min_length, 20);
+        assert_eq!(feat.settings.header.type_enum, rules.settings.header.type_enum);
+
+        assert_eq!(&*fix.kind, "fix");
+        assert!(fix.set.contains(Rule::FooterExists));
+        assert!(!fix.set.contains(Rule::BodyMinLength));
+        assert_eq!(fix.settings.footer.required, [Box::<str>::from("Refs")]);
+
+        let invalid = CommitSettings::from_toml("[lint.overrides]\nfix = 1");
+        assert!(matches!(invalid, Err(Error::UnexpectedFieldType(_, _))));
+    }
+
+    #[test]
+    fn test_rules_settings_levels() {
+        let mut settings = CommitSettings::from_toml(
+            r#"
+[lint.header]
+type-enum = { level = "warn", value = ["feat", "fix"] }
+scope-required = { level = "warn" }
+description-full-stop = { level = "off" }
+max-length = { level = "error", value = 72 }
+
+[lint.footer]
+key-enum = { level = "warn", keys = ["Refs"] }
+
+[lint.overrides.fix.header]
+type-enum = ["fix"]
+"#,
+        )
+        .unwrap();
+        let rules = &settings.rules;
+
+        assert!(rules.set.contains(Rule::HeaderTypeEnum));
+        assert!(rules.set.contains(Rule::HeaderScopeRequired));
+        assert!(rules.set.contains(Rule::FooterKeyEnum));
+        assert!(!rules.set.contains(Rule::HeaderDescriptionFullStop));
+        assert_eq!(rules.settings.header.max_length, 72);
+        assert_eq!(rules.settings.footer.key_enum, [Box::<str>::from("Refs")]);
+        assert_eq!(
+            rules.warnings,
+            RuleSet::from_rules(&[
+                Rule::HeaderTypeEnum,
+                Rule::HeaderScopeRequired,
+                Rule::FooterKeyEnum
+            ])
+        );
+        assert_eq!(
+            rules.overrides[0].warnings,
+            RuleSet::from_rules(&[Rule::HeaderScopeRequired, Rule::FooterKeyEnum])
+        );
+
+        settings.rules.deny_warnings();
+        assert!(settings.rules.warnings.is_empty());
+        assert!(settings.rules.overrides[0].warnings.is_empty());
+
+        let invalid = CommitSettings::from_toml(
+            "[lint.header]
+type-required = { level = 1 }",
+        );
+        assert!(matches!(invalid, Err(Error::InvalidRuleLevel(_))));
+    }
+
+    #[test]
+    fn test_breaking_key() {
+        let settings =
+            CommitSettings::from_toml("breaking-key = \"BREAKING-CHANGE\"").unwrap();
+        assert_eq!(settings.breaking_key.as_deref(), Some("BREAKING-CHANGE"));
+
+        assert!(matches!(
+            CommitSettings::from_toml("breaking-key = \"Breaking\""),
+            Err(Error::InvalidBreakingKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_ignore_settings_from_toml() {
+        let settings = CommitSettings::from_toml(
+            r#"
+[ignore]
+patterns = ["^fixup! ", "^Initial commit$"]
+authors = ["^dependabot\\[bot\\]"]
+merges = false
+"#,
+        )
+        .unwrap();
+        let ignore = settings.ignore;
+
+        assert!(ignore.is_ignored("fixup! feat: x", None, 1));
+        assert!(ignore.is_ignored("Initial commit", None, 1));
+        assert!(ignore.is_ignored("Initial commit\n", None, 1));
+        assert!(!ignore.is_ignored("Initial commit\n\nbody\n", None, 1));
+        assert!(!ignore.is_ignored("Merge branch 'main'", None, 2));
+        assert!(ignore.is_ignored(
+            "chore(deps): bump",
+            Some("dependabot[bot] <support@github.com>"),
+            1
+        ));
+        assert!(!ignore.is_ignored("chore(deps): bump", Some("Dev <dev@example.com>"), 1));
+        assert!(!ignore.is_ignored("chore(deps): bump", None, 1));
+    }
+
+    #[test]
+    fn test_ignore_settings_rejects_invalid_pattern() {
+        assert!(matches!(
+            CommitSettings::from_toml("[ignore]\npatterns = [\"(\"]"),
+            Err(Error::InvalidPattern(_))
+        ));
+    }
+
+    #[test]
+    fn test_ignore_settings_disable_defaults() {
+        let settings = CommitSettings::from_toml("[ignore]\npatterns = []").unwrap();
+        assert!(!settings.ignore.is_ignored("Merge branch 'main'", None, 1));
+        assert!(settings.ignore.is_ignored("Merge branch 'main'", None, 2));
+    }
+
+    #[test]
+    fn test_ignore_settings_default_merge_commit() {
+        let ignore = IgnoreSettings::default();
+        let merge_messages = vec![
+            "Merge branch 'main' into test",
+            "Merge branches 'feature/auth' and 'feature/payments' into main",
+            "Merge tag 'v1.0.0' into main",
+            "Merge pull request #123 from feature/auth",
+            "Merge commit 'a1b2c3d' into main",
+            "Merge remote-tracking branch 'origin/main'",
+            "Merge",
+        ];
+
+        for message in merge_messages {
+            assert!(
+                ignore.is_ignored(message, None, 1),
+                "Expected merge message to be ignored: '{message}'"
+            );
+        }
+        assert!(ignore.is_ignored("feat: merged by topology", None, 2));
+    }
+
+    #[test]
+    fn test_ignore_settings_default_revert_commit() {
+        let ignore = IgnoreSettings::default();
+        let revert_messages = vec![
+            "Revert \"feat: add new feature\"",
+            "Revert commit a1b2c3d",
+            "Revert changes from PR #123",
+            "Revert",
+        ];
+
+        for message in revert_messages {
+            assert!(
+                ignore.is_ignored(message, None, 1),
+                "Expected revert message to be ignored: '{message}'"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ignore_settings_default_regular_commit() {
+        let ignore = IgnoreSettings::default();
+        let regular_messages = vec![
+            "feat: add new feature",
+            "fix: resolve bug in authentication",
+            "docs: update README",
+            "refactor: improve code structure",
+            "test: add unit tests",
+            "chore: update dependencies",
+            "Initial commit",
+            "WIP: work in progress",
+            "Some random commit message",
+            // Edge cases - messages that contain merge/revert but don't start with them
+            "feat: merge two functions into one",
+            "fix: revert unwanted changes manually",
+            "Some commit that mentions Merge in the middle",
+            "Another commit about Revert functionality",
+        ];
+
+        for message in regular_messages {
+            assert!(
+                !ignore.is_ignored(message, None, 1),
+                "Expected regular message to not be ignored: '{message}'"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ignore_settings_default_empty_and_whitespace() {
+        let ignore = IgnoreSettings::default();
+        let edge_cases = vec!["", " ", "\n", "\t", "   \n\t   "];
+
+        for message in edge_cases {
+            assert!(
+                !ignore.is_ignored(message, None, 1),
+                "Expected empty/whitespace message to not be ignored: '{message:?}'"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ignore_settings_default_case_sensitivity() {
+        let ignore = IgnoreSettings::default();
+        assert!(ignore.is_ignored("Merge branch 'main'", None, 1));
+        assert!(!ignore.is_ignored("merge branch 'main'", None, 1));
+        assert!(!ignore.is_ignored("MERGE branch 'main'", None, 1));
+
+        assert!(ignore.is_ignored("Revert commit", None, 1));
+        assert!(!ignore.is_ignored("revert commit", None, 1));
+        assert!(!ignore.is_ignored("REVERT commit", None, 1));
+    }
+
+    #[test]
+    fn test_ignore_settings_default_multiline() {
+        let ignore = IgnoreSettings::default();
+        let multiline_merge =
+            "Merge branch 'feature/auth'\n\nThis merge includes authentication improvements";
+        let multiline_revert =
+            "Revert \"feat: add feature\"\n\nThis reverts commit a1b2c3d due to bugs";
+        let multiline_regular =
+            "feat: add authentication\n\nImplement OAuth2 flow\nAdd user management";
+
+        assert!(ignore.is_ignored(multiline_merge, None, 1));
+        assert!(ignore.is_ignored(multiline_revert, None, 1));
+        assert!(!ignore.is_ignored(multiline_regular, None, 1));
+    }
 }
@@ -0,0 +1,168 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{Error, Result};
+
+/// Name of the baseline file in the repository root.
+pub const BASELINE_FILE_NAME: &str = ".commitfmt-baseline";
+
+/// First line of the written baseline file.
+const HEADER: &str =
+    "# Violations recorded by `commitfmt baseline write`, one `<sha> <rule>` per line";
+
+/// Violations of the existing history that are not reported by range linting.
+///
+/// Each entry is a full commit sha and a rule id in the `group.rule` form.
+/// Entries are kept sorted, so the checked-in file has stable diffs.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    entries: BTreeSet<(String, String)>,
+}
+
+impl Baseline {
+    /// Parses the baseline file content. Empty lines and lines starting with `#` are skipped.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut baseline = Self::default();
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+            let (Some(sha), Some(rule_id), None) = (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(Error::InvalidBaseline(index + 1));
+            };
+            if !is_full_sha(sha) || !rule_id.contains('.') {
+                return Err(Error::InvalidBaseline(index + 1));
+            }
+            baseline.insert(sha, rule_id);
+        }
+
+        Ok(baseline)
+    }
+
+    /// Reads the baseline file. A missing file is an empty baseline.
+    pub fn read(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Writes the baseline file.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    /// Adds the violation of the rule in the commit.
+    pub fn insert(&mut self, sha: &str, rule_id: &str) {
+        self.entries.insert((sha.to_string(), rule_id.to_string()));
+    }
+
+    /// Checks that the violation of the rule in the commit is recorded.
+    pub fn contains(&self, sha: &str, rule_id: &str) -> bool {
+        self.entries.contains(&(sha.to_string(), rule_id.to_string()))
+    }
+
+    /// Removes the entries of commits that are not in `commits`.
+    ///
+    /// Returns the number of removed entries.
+    pub fn retain_commits(&mut self, commits: &HashSet<String>) -> usize {
+        let len = self.entries.len();
+        self.entries.retain(|(sha, _)| commits.contains(sha));
+        len - self.entries.len()
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Checks that the sha is a full SHA-1 or SHA-256 object name.
+fn is_full_sha(sha: &str) -> bool {
+    matches!(sha.len(), 40 | 64) && sha.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for (sha, rule_id) in &self.entries {
+            writeln!(f, "{sha} {rule_id}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHA: &str = "4d71944a9f0c3e1b2d5a6c7e8f9012345678abcd";
+    const OTHER_SHA: &str = "22ade62b9f0c3e1b2d5a6c7e8f9012345678abcd";
+
+    #[test]
+    fn test_parse_and_display() {
+        let input = format!(
+            "# comment\n\n{OTHER_SHA} header.type-enum\n{SHA} header.type-enum\n{SHA}  body.max-line-length\n"
+        );
+        let baseline = Baseline::parse(&input).unwrap();
+        assert_eq!(baseline.len(), 3);
+        assert!(baseline.contains(SHA, "header.type-enum"));
+        assert!(baseline.contains(SHA, "body.max-line-length"));
+        assert!(!baseline.contains(SHA, "header.scope-enum"));
+
+        assert_eq!(
+            baseline.to_string(),
+            format!(
+                "{HEADER}\n\
+                 {OTHER_SHA} header.type-enum\n\
+                 {SHA} body.max-line-length\n\
+                 {SHA} header.type-enum\n"
+            )
+        );
+        assert_eq!(Baseline::parse(&baseline.to_string()).unwrap(), baseline);
+    }
+
+    #[test]
+    fn test_parse_invalid_entry() {
+        let sha256 = SHA.repeat(2);
+        assert!(Baseline::parse(&format!("{} header.type-enum", &sha256[..64])).is_ok());
+
+        for input in [
+            SHA,
+            "not-a-sha header.type-enum",
+            "4d71944 header.type-enum",
+            &format!("{SHA}0 header.type-enum"),
+            &format!("{SHA} type-enum"),
+        ] {
+            let result = Baseline::parse(&format!("# comment\n{input}"));
+            assert!(matches!(result, Err(Error::InvalidBaseline(2))), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_retain_commits() {
+        let mut baseline = Baseline::default();
+        baseline.insert(SHA, "header.type-enum");
+        baseline.insert(SHA, "body.case");
+        baseline.insert(OTHER_SHA, "header.type-enum");
+
+        let commits = HashSet::from([SHA.to_string()]);
+        assert_eq!(baseline.retain_commits(&commits), 1);
+        assert_eq!(baseline.len(), 2);
+        assert!(!baseline.contains(OTHER_SHA, "header.type-enum"));
+    }
+}
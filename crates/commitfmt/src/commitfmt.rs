@@ -1,28 +1,36 @@
+use std::cell::OnceCell;
 use std::collections::HashMap;
-use std::path::Path;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
 use colored::Colorize;
 
-use commitfmt_cc::{Footer, Message};
-use commitfmt_git::Repository;
-use commitfmt_linter::{Check, FixMode, Rule, Violation};
+use commitfmt_cc::{Edit, Footer, Message, SyntaxKind, SyntaxTree};
+use commitfmt_git::{LogOptions, Repository};
+use commitfmt_linter::{Check, FixMode, Rule, Severity, Violation};
 use commitfmt_workspace::{open_settings, AdditionalFooter, CommitSettings, OnConflictAction};
 
-use crate::ignore::is_ignored_message;
+use crate::baseline::{Baseline, BASELINE_FILE_NAME};
 use crate::logging::pluralize;
-use crate::{print_error, print_info};
+use crate::report::{CommitReport, Diagnostic};
+use crate::{print_error, print_info, print_warning};
 use crate::{CommitRange, Error, Result};
 
 /// Commitfmt application.
 pub struct Commitfmt {
     pub repo: Repository,
     pub settings: CommitSettings,
+    /// Traversal options used to read commit ranges
+    pub log_options: LogOptions,
+    /// Violations that are not reported by range linting.
+    /// The file is read on first use, since only range linting needs it.
+    baseline: OnceCell<Baseline>,
 }
 
 impl Commitfmt {
     /// Creates a new Commitfmt application with workspace from the given path.
     pub fn new(repo: Repository, settings: CommitSettings) -> Self {
-        Self { repo, settings }
+        Self { repo, settings, log_options: LogOptions::default(), baseline: OnceCell::new() }
     }
 
     /// Creates a new Commitfmt application with workspace from the given path.
@@ -42,20 +50,85 @@ impl Commitfmt {
         Ok(Self::new(repo, settings))
     }
 
-    /// Lint a commit range (from..to).
-    pub fn lint_commit_range(&self, range: CommitRange) -> Result<()> {
+    /// Returns the path to the baseline file in the repository root.
+    pub fn baseline_path(&self) -> PathBuf {
+        self.repo.get_root().join(BASELINE_FILE_NAME)
+    }
+
+    /// Returns the baseline, reading the baseline file on first use.
+    pub fn baseline(&self) -> Result<&Baseline> {
+        if let Some(baseline) = self.baseline.get() {
+            return Ok(baseline);
+        }
+
+        let baseline = Baseline::read(&self.baseline_path())?;
+        Ok(self.baseline.get_or_init(|| baseline))
+    }
+
+    /// Checks a commit range (from..to) without reporting.
+    ///
+    /// Returns a report for each commit in the range, including ignored ones.
+    /// Violations recorded in the baseline are not reported.
+    pub fn check_commit_range(&self, range: CommitRange) -> Result<Vec<CommitReport>> {
+        let commits = self.check_commits(range, self.baseline()?)?;
+        Ok(commits.into_iter().map(|(_, report)| report).collect())
+    }
+
+    /// Records the violations of the commit range to the baseline file.
+    ///
+    /// The existing baseline is replaced. Returns the number of recorded entries.
+    pub fn write_baseline(&mut self, range: CommitRange) -> Result<usize> {
+        let mut baseline = Baseline::default();
+        for (sha, report) in self.check_commits(range, &Baseline::default())? {
+            for diagnostic in &report.diagnostics {
+                baseline.insert(&sha, &diagnostic.rule_id());
+            }
+        }
+
+        baseline.write(&self.baseline_path())?;
+        let count = baseline.len();
+        self.baseline = OnceCell::from(baseline);
+        Ok(count)
+    }
+
+    /// Removes the baseline entries of commits that are not reachable anymore,
+    /// e.g. after the history is rewritten.
+    ///
+    /// Returns the number of removed entries.
+    pub fn prune_baseline(&mut self) -> Result<usize> {
+        let commits = self.repo.reachable_commits()?;
+        let path = self.baseline_path();
+        self.baseline()?;
+        let baseline = self.baseline.get_mut().expect("the baseline is read");
+        let removed = baseline.retain_commits(&commits);
+        if removed > 0 {
+            baseline.write(&path)?;
+        }
+        Ok(removed)
+    }
+
+    /// Checks a commit range (from..to) and drops the violations recorded in `baseline`.
+    ///
+    /// Returns the full sha and the report of each commit.
+    fn check_commits(
+        &self,
+        range: CommitRange,
+        baseline: &Baseline,
+    ) -> Result<Vec<(String, CommitReport)>> {
         let (from, to) = range;
-        let commits = self.repo.stream_log(from, to)?;
+        let commits = self.repo.stream_log_with(from, to, self.log_options)?;
 
-        let mut problems_count: usize = 0;
-        let mut commits_count: usize = 0;
-        let mut check = Check::new(&self.settings.rules.settings, self.settings.rules.set);
+        let mut reports = Vec::new();
+        let mut check = self.new_check();
 
         for commit in commits {
             let commit = commit?;
-            commits_count += 1;
-            if is_ignored_message(&commit.message) {
+            let author = commit.author.to_string();
+            let ignore = &self.settings.ignore;
+            if ignore.is_ignored(&commit.message, Some(&author), commit.parents.len()) {
                 // Skip ignored commits.
+                let report = CommitReport::ignored(Some(commit.sha)).with_author(author);
+                reports.push((commit.full_sha, report));
                 continue;
             }
             let message = Message::parse(
@@ -64,128 +137,327 @@ impl Commitfmt {
                 self.settings.comment_symbol.as_deref(),
             );
 
+            check.set_author(Some(author.clone()));
             check.lint(&message);
-            if !check.report.violations.is_empty() {
-                let count = check.report.violations.len();
-                let sha = &commit.sha;
-                if count == 1 {
-                    print_error!("Commit {sha} has violation");
-                } else {
-                    print_error!("Commit {sha} has {count} violations");
-                }
+            let report = std::mem::take(&mut check.report);
+            let mut report = CommitReport::new(Some(commit.sha), report).with_author(author);
+            report.diagnostics.retain(|diagnostic| {
+                !baseline.contains(&commit.full_sha, &diagnostic.rule_id())
+            });
+            reports.push((commit.full_sha, report));
+        }
+
+        Ok(reports)
+    }
+
+    /// Lint a commit range (from..to).
+    pub fn lint_commit_range(&self, range: CommitRange) -> Result<()> {
+        let reports = self.check_commit_range(range)?;
 
-                let _ = report_violations(check.report.violations.iter());
-                problems_count += count;
-                check.report.violations.clear();
+        let mut problems_count: usize = 0;
+        let mut warnings_count: usize = 0;
+        for report in &reports {
+            if report.diagnostics.is_empty() {
+                continue;
+            }
+
+            let count = report.diagnostics.len();
+            let sha = report.sha.as_deref().unwrap_or_default();
+            if report.problems_count() == 0 {
+                let warning_pluralized = pluralize(count, "warning", "warnings");
+                print_warning!("Commit {sha} has {count} {warning_pluralized}");
+            } else if count == 1 {
+                print_error!("Commit {sha} has violation");
+            } else {
+                print_error!("Commit {sha} has {count} violations");
             }
+
+            report_violations(&report.diagnostics);
+            problems_count += report.problems_count();
+            warnings_count += report.warnings_count();
         }
 
         if problems_count > 0 {
             return Err(Error::Lint(problems_count));
         }
 
+        let commits_count = reports.len();
         let commit_pluralized = pluralize(commits_count, "commit", "commits");
-        print_info!("No problems found in {commits_count} {commit_pluralized}");
+        if warnings_count > 0 {
+            let warning_pluralized = pluralize(warnings_count, "warning", "warnings");
+            print_warning!(
+                "No errors found in {commits_count} {commit_pluralized}, {warnings_count} {warning_pluralized}"
+            );
+        } else {
+            print_info!("No problems found in {commits_count} {commit_pluralized}");
+        }
         Ok(())
     }
 
-    fn check_message(&self, input: &str) -> (Message, Check<'_>) {
-        let message = Message::parse(
+    /// Checks if the message of the commit in progress is ignored.
+    pub fn is_ignored_message(&self, input: &str) -> bool {
+        let ignore = &self.settings.ignore;
+        // The author is resolved only when needed because it spawns git
+        let author = if ignore.authors.is_empty() { None } else { self.repo.author_ident() };
+        let parents_count = 1 + self.repo.merge_heads().len();
+
+        ignore.is_ignored(input, author.as_deref(), parents_count)
+    }
+
+    /// Creates a check with the configured rules.
+    fn new_check(&self) -> Check<'_> {
+        let rules = &self.settings.rules;
+        Check::new(&rules.settings, rules.set)
+            .with_warnings(rules.warnings)
+            .with_overrides(&rules.overrides)
+    }
+
+    fn parse_message(&self, input: &str) -> Message {
+        Message::parse(
             input,
             self.settings.footer_separators.as_deref(),
             self.settings.comment_symbol.as_deref(),
-        );
+        )
+    }
 
-        let mut check = Check::new(&self.settings.rules.settings, self.settings.rules.set);
-        check.lint(&message);
-        (message, check)
+    fn check_message(&self, message: &Message) -> Check<'_> {
+        let mut check = self.new_check();
+        // The author is resolved only when needed because it spawns git
+        if self.settings.rules.uses(Rule::FooterSignedOffBy) {
+            check.set_author(self.repo.author_ident());
+        }
+        check.lint(message);
+        check
+    }
+
+    /// Checks a commit message without reporting.
+    pub fn check_commit_message(&self, input: &str) -> CommitReport {
+        let check = self.check_message(&self.parse_message(input));
+        CommitReport::new(None, check.report)
     }
 
     /// Lints a commit message without formatting it.
+    ///
+    /// Warnings are reported, but don't fail the check.
     pub fn lint_commit_message(&self, input: &str) -> Result<()> {
-        let (_, check) = self.check_message(input);
-        if check.report.violations.is_empty() {
-            return Ok(());
+        let report = self.check_commit_message(input);
+        report_violations(&report.diagnostics);
+        match report.problems_count() {
+            0 => Ok(()),
+            count => Err(Error::Lint(count)),
         }
-        let count = report_violations(check.report.violations.iter());
-        Err(Error::Lint(count))
+    }
+
+    /// Checks a commit message and applies the fixes allowed by the settings.
+    ///
+    /// The fixes are applied as minimal edits, so the rest of the text is kept as is.
+    /// If a fix has no edits, the fixed message is formatted as a whole.
+    /// Returns the fixed text and the report with the fix status of each violation.
+    pub fn fix_commit_message(&self, input: &str) -> (String, CommitReport) {
+        let tree = SyntaxTree::parse(
+            input,
+            self.settings.footer_separators.as_deref(),
+            self.settings.comment_symbol.as_deref(),
+        );
+        let check = self.check_message(tree.message());
+
+        let mut report = CommitReport::default();
+        let mut edits = Some(self.breaking_key_edits(&tree));
+        for (violation, severity) in check.report.into_violations() {
+            let fixed = match violation.fix_mode() {
+                FixMode::Safe => true,
+                FixMode::Unsafe => self.settings.lint.unsafe_fixes,
+                FixMode::Unfixable => false,
+            };
+            if fixed {
+                edits =
+                    edits.zip(violation.edits(&tree)).map(|(mut edits, violation_edits)| {
+                        edits.extend(violation_edits);
+                        edits
+                    });
+            }
+            report.diagnostics.push(Diagnostic { violation, severity, fixed });
+        }
+
+        let output = match edits {
+            Some(mut edits) => {
+                // Violations of the same rule may produce the same edit
+                edits.sort_by_key(|edit| (edit.range.start, edit.range.end));
+                edits.dedup();
+                tree.apply(&edits)
+            }
+            None => self.fix_message(tree.message().clone(), &report),
+        };
+
+        (output, report)
+    }
+
+    /// Returns the edits that rename breaking change footers to the configured key
+    fn breaking_key_edits(&self, tree: &SyntaxTree) -> Vec<Edit> {
+        let Some(key) = &self.settings.breaking_key else {
+            return Vec::new();
+        };
+
+        tree.footers()
+            .filter(|(footer, _)| footer.is_breaking_change() && &footer.key != key)
+            .filter_map(|(_, node)| node.child(SyntaxKind::FooterKey))
+            .map(|node| Edit::replace(node.range.clone(), key.as_str()))
+            .collect()
+    }
+
+    /// Applies the fixes to the parsed message and formats it
+    fn fix_message(&self, mut message: Message, report: &CommitReport) -> String {
+        for diagnostic in report.diagnostics.iter().filter(|diagnostic| diagnostic.fixed) {
+            diagnostic.violation.fix(&mut message).expect("Failed to fix violation");
+        }
+
+        if let Some(key) = &self.settings.breaking_key {
+            for footer in
+                message.footers.iter_mut().filter(|footer| footer.is_breaking_change())
+            {
+                footer.key.clone_from(key);
+            }
+        }
+
+        message.to_string()
     }
 
     /// Formats a commit message.
+    ///
+    /// The fixes and the configured additional footers are applied as minimal edits,
+    /// so the rest of the text is kept byte-for-byte.
     pub fn format_commit_message(&self, input: &str) -> Result<String> {
-        let (mut message, check) = self.check_message(input);
-
-        let mut unfixable_count: usize = 0;
-        let message_ptr = &mut message;
-        for violation_box in &check.report.violations {
-            let violation = violation_box.as_ref();
-            match violation.fix_mode() {
-                FixMode::Unsafe => {
-                    if self.settings.lint.unsafe_fixes {
-                        violation.fix(message_ptr).expect("Failed to fix violation");
-                    } else {
-                        print_violation(violation, true);
-                        unfixable_count += 1;
-                    }
-                }
-                FixMode::Safe => {
-                    violation.fix(message_ptr).expect("Failed to fix violation");
-                }
-                FixMode::Unfixable => {
-                    print_violation(violation, false);
-                    unfixable_count += 1;
-                }
-            }
+        let output = self.apply_fixes(input)?;
+        self.append_footer_edits(&output)
+    }
+
+    /// Formats a commit message and normalizes it as a whole.
+    ///
+    /// Unlike [`Commitfmt::format_commit_message`], the untouched text is rewritten too.
+    pub fn normalize_commit_message(&self, input: &str) -> Result<String> {
+        let output = self.apply_fixes(input)?;
+        self.format_message(&output)
+    }
+
+    /// Applies the fixes and prints the violations that are left.
+    /// Fails if any of them is a problem.
+    fn apply_fixes(&self, input: &str) -> Result<String> {
+        let (output, report) = self.fix_commit_message(input);
+
+        for diagnostic in report.diagnostics.iter().filter(|diagnostic| !diagnostic.fixed) {
+            let violation = diagnostic.violation.as_ref();
+            let fix_available = violation.fix_mode() == FixMode::Unsafe;
+            print_violation(violation, diagnostic.severity, fix_available);
         }
 
+        let unfixable_count = report.problems_count();
         if unfixable_count > 0 {
             return Err(Error::Unfixable(unfixable_count));
         }
 
+        Ok(output)
+    }
+
+    /// Normalizes the message and appends the configured additional footers.
+    pub fn format_message(&self, input: &str) -> Result<String> {
+        let mut message = self.parse_message(input);
+        self.append_footers(&mut message)?;
+        Ok(message.to_string())
+    }
+
+    /// Appends the configured additional footers as an edit of the message text.
+    /// The rest of the text is kept as is.
+    pub fn append_footer_edits(&self, input: &str) -> Result<String> {
+        let tree = SyntaxTree::parse(
+            input,
+            self.settings.footer_separators.as_deref(),
+            self.settings.comment_symbol.as_deref(),
+        );
+        let mut message = tree.message().clone();
+        let count = message.footers.len();
+        self.append_footers(&mut message)?;
+
+        // All footers are inserted at once, after the last footer of the text
+        let mut footers = message.footers.iter().skip(count);
+        let Some(mut edit) = footers.next().and_then(|footer| tree.append_footer(footer))
+        else {
+            return Ok(input.to_string());
+        };
+        for footer in footers {
+            write!(edit.content, "\n{footer}").expect("Failed to write to string");
+        }
+
+        Ok(tree.apply(&[edit]))
+    }
+
+    /// Appends configured additional footers to the message.
+    pub fn append_footers(&self, message: &mut Message) -> Result<()> {
         let footers = self.settings.footers.borrow();
         if footers.is_empty() {
-            return Ok(message.to_string());
+            return Ok(());
         }
 
-        let Some(branch) = self.repo.get_branch_name() else {
-            return Ok(message.to_string());
+        let branch = match self.current_branch() {
+            Ok(branch) => Some(branch),
+            Err(Error::DetachedHead) => {
+                if footers.iter().any(|footer| footer.branch_pattern.is_some()) {
+                    print_warning!(
+                        "{}, skipping footers with branch pattern",
+                        Error::DetachedHead
+                    );
+                }
+                None
+            }
+            // Branch footers are skipped if HEAD can't be read, e.g. in a broken repository
+            Err(_) => None,
         };
 
-        append_footers(&footers, &mut message, &branch)?;
+        append_footers(&footers, message, branch.as_deref())
+    }
 
-        Ok(message.to_string())
+    /// Returns the name of the branch the commit is made on.
+    /// The branch is resolved during rebase and bisect, when HEAD is detached.
+    pub fn current_branch(&self) -> Result<String> {
+        match self.repo.head()?.branch_name() {
+            Some(branch) => Ok(branch.to_string()),
+            None => Err(Error::DetachedHead),
+        }
     }
 }
 
-/// Reports all violations to the logger and returns the total count.
+/// Reports all violations to the logger.
 ///
-/// This function iterates through the provided violations, logs each one
-/// with its message and rule identifier, and keeps track of the total count.
-fn report_violations<'a>(violations: impl Iterator<Item = &'a Box<dyn Violation>>) -> usize {
-    let mut count: usize = 0;
-    for violation_box in violations {
-        count += 1;
-        print_violation(violation_box.as_ref(), false);
+/// This function iterates through the provided diagnostics and logs each one
+/// with its severity, message and rule identifier.
+fn report_violations(diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        print_violation(diagnostic.violation.as_ref(), diagnostic.severity, false);
     }
-
-    count
 }
 
 /// Prints a single violation to the logger
-fn print_violation(violation: &dyn Violation, fix_available: bool) {
+fn print_violation(violation: &dyn Violation, severity: Severity, fix_available: bool) {
     let Some(rule) = Rule::from_violation(violation) else {
         panic!("Failed to get rule from violation");
     };
     let rule_name = format!("[{}]", rule.as_display());
+    let location = match violation.span() {
+        Some(span) => format!("{} ", span.to_string().dimmed()),
+        None => String::new(),
+    };
+    let label = match severity {
+        Severity::Error => String::new(),
+        Severity::Warning => format!("{} ", "warning:".yellow()),
+    };
     let line = if fix_available {
         format!(
-            "- {} {} {}",
+            "- {location}{label}{} {} {}",
             violation.message(),
             rule_name.dimmed(),
             "(unsafe fix available)".bright_yellow()
         )
     } else {
-        format!("- {} {}", violation.message(), rule_name.dimmed())
+        format!("- {location}{label}{} {}", violation.message(), rule_name.dimmed())
     };
     print_info!("{line}");
 }
@@ -193,11 +465,11 @@ fn print_violation(violation: &dyn Violation, fix_available: bool) {
 /// Appends footers to the message
 ///
 /// This function iterates through the provided footers, renders their values with the given branch,
-/// and appends them to the message.
+/// and appends them to the message. Footers with branch pattern are skipped if there is no branch.
 fn append_footers(
     footers: &[AdditionalFooter],
     message: &mut Message,
-    branch: &str,
+    branch: Option<&str>,
 ) -> Result<()> {
     for footer in footers {
         if message.footers.contains_key(&footer.key) {
@@ -216,7 +488,7 @@ fn append_footers(
 
         let mut variables = HashMap::new();
         if let Some(branch_pattern) = &footer.branch_pattern {
-            let Some(caps) = branch_pattern.captures(branch) else {
+            let Some(caps) = branch.and_then(|branch| branch_pattern.captures(branch)) else {
                 continue;
             };
             for (i, name) in branch_pattern.capture_names().enumerate() {
@@ -230,7 +502,13 @@ fn append_footers(
         let alignment = footer.alignment.unwrap_or_default();
 
         let value = footer.value.render(&variables)?;
-        message.footers.push(Footer { key: footer.key.clone(), value, separator, alignment });
+        message.footers.push(Footer {
+            key: footer.key.clone(),
+            value,
+            separator,
+            alignment,
+            span: None,
+        });
     }
     Ok(())
 }
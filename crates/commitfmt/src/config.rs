@@ -0,0 +1,229 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use clap::ValueEnum;
+use serde_json::{json, Map, Value};
+
+use commitfmt_git::Repository;
+use commitfmt_workspace::{open_effective_config, EffectiveConfig, Origin, OriginTable};
+
+use crate::Result;
+
+/// Output format of the effective config.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ConfigFormat {
+    /// TOML with the origin of each value in a comment
+    #[default]
+    Toml,
+    /// JSON with the `value` and `origin` of each value
+    Json,
+}
+
+/// Opens the effective config of the directory.
+///
+/// Like the formatter, it falls back to the git config for the comment symbol
+/// and the footer separators if the directory is in a repository.
+pub fn effective_config(path: &Path) -> Result<EffectiveConfig> {
+    let mut config = open_effective_config(path)?;
+
+    if let Ok(repo) = Repository::open(path) {
+        let repo_config = repo.message_config();
+        if let Some(comment_symbol) = repo_config.comment_symbol {
+            config.apply_fallback("comment-symbol", &comment_symbol, Origin::GitConfig);
+        }
+        if let Some(separators) = repo_config.trailer_separators {
+            config.apply_fallback("footer-separators", &separators, Origin::GitConfig);
+        }
+    }
+
+    Ok(config)
+}
+
+/// Writes the effective config in the format.
+pub fn render_config(config: &EffectiveConfig, format: ConfigFormat) -> String {
+    match format {
+        ConfigFormat::Toml => render_toml(config),
+        ConfigFormat::Json => {
+            let mut output = serde_json::to_string_pretty(&config_to_json(config))
+                .expect("JSON value is serializable");
+            output.push('\n');
+            output
+        }
+    }
+}
+
+fn render_toml(config: &EffectiveConfig) -> String {
+    let mut output = String::new();
+    if config.files.is_empty() {
+        output.push_str("# No config file found\n");
+    }
+    for (path, origin) in &config.files {
+        writeln!(output, "# {}: {}", origin.as_display(), path.display()).unwrap();
+    }
+
+    for table in &config.tables {
+        if table.path.is_empty() {
+            write_values(&mut output, table);
+            continue;
+        }
+        if table.values.is_empty() && !table.is_array_item {
+            continue;
+        }
+
+        let path = table.path.iter().map(|key| toml_key(key)).collect::<Vec<_>>().join(".");
+        if table.is_array_item {
+            writeln!(output, "\n[[{path}]]").unwrap();
+        } else {
+            writeln!(output, "\n[{path}]").unwrap();
+        }
+        write_values(&mut output, table);
+    }
+
+    output
+}
+
+fn write_values(output: &mut String, table: &OriginTable) {
+    for entry in &table.values {
+        let key = toml_key(&entry.key);
+        writeln!(output, "{key} = {} # {}", entry.value, entry.origin.as_display()).unwrap();
+    }
+}
+
+/// Returns the key as is if it's a bare TOML key, otherwise quoted.
+fn toml_key(key: &str) -> String {
+    let is_bare = !key.is_empty()
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if is_bare {
+        key.to_string()
+    } else {
+        // JSON string escapes are valid in TOML basic strings
+        Value::from(key).to_string()
+    }
+}
+
+fn config_to_json(config: &EffectiveConfig) -> Value {
+    let mut root = Map::new();
+
+    for table in &config.tables {
+        let values: Map<String, Value> = table
+            .values
+            .iter()
+            .map(|entry| {
+                let value = serde_json::to_value(&entry.value).unwrap_or(Value::Null);
+                (
+                    entry.key.clone(),
+                    json!({ "value": value, "origin": entry.origin.as_display() }),
+                )
+            })
+            .collect();
+
+        let Some((last, parents)) = table.path.split_last() else {
+            root.extend(values);
+            continue;
+        };
+        let mut parent = &mut root;
+        for key in parents {
+            parent = parent
+                .entry(key.clone())
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .expect("config table is an object");
+        }
+
+        if table.is_array_item {
+            let items = parent.entry(last.clone()).or_insert_with(|| Value::Array(Vec::new()));
+            if let Value::Array(items) = items {
+                items.push(Value::Object(values));
+            }
+        } else {
+            let object =
+                parent.entry(last.clone()).or_insert_with(|| Value::Object(Map::new()));
+            if let Value::Object(object) = object {
+                object.extend(values);
+            }
+        }
+    }
+
+    Value::Object(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn config(content: &str) -> EffectiveConfig {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("commitfmt.toml"), content).unwrap();
+        let mut config = open_effective_config(dir.path()).unwrap();
+        config.files.clear();
+        config
+    }
+
+    #[test]
+    fn test_toml_key() {
+        assert_eq!(toml_key("type-enum"), "type-enum");
+        assert_eq!(toml_key("my type"), "\"my type\"");
+        assert_eq!(toml_key(""), "\"\"");
+    }
+
+    #[test]
+    fn test_render_toml() {
+        let config = config(
+            "[lint.header]
+type-enum = [\"feat\", \"fix\"]
+
+[lint.overrides.\"my type\"]
+skip = true
+
+[[additional-footers]]
+key = \"Ticket\"
+value = \"1\"
+",
+        );
+
+        let output = render_config(&config, ConfigFormat::Toml);
+        assert!(output.starts_with(
+            "# No config file found\n\
+             footer-separators = \":\" # default\n\
+             comment-symbol = \"#\" # default\n\
+             \n[lint]\n\
+             unsafe-fixes = false # default\n\
+             \n[lint.header]\n\
+             type-enum = [\"feat\", \"fix\"] # child config\n\
+             description-full-stop = true # default\n"
+        ));
+        assert!(
+            output.contains("\n[lint.overrides.\"my type\"]\nskip = true # child config\n")
+        );
+        assert!(output.ends_with(
+            "\n[[additional-footers]]\nkey = \"Ticket\" # child config\nvalue = \"1\" # child config\n"
+        ));
+    }
+
+    #[test]
+    fn test_render_json() {
+        let config = config(
+            "[lint.body]
+max-line-length = 72
+
+[[additional-footers]]
+key = \"Ticket\"
+value = \"1\"
+",
+        );
+
+        let value: Value =
+            serde_json::from_str(&render_config(&config, ConfigFormat::Json)).unwrap();
+        assert_eq!(value["comment-symbol"], json!({ "value": "#", "origin": "default" }));
+        assert_eq!(
+            value["lint"]["body"]["max-line-length"],
+            json!({ "value": 72, "origin": "child config" })
+        );
+        assert_eq!(
+            value["additional-footers"][0]["key"],
+            json!({ "value": "Ticket", "origin": "child config" })
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use std::io::Write;
+
+use crate::emitter::Emitter;
+use crate::report::CommitReport;
+
+/// Emits unfixed violations as GitHub Actions `::error` and `::warning` workflow commands.
+pub struct GithubEmitter;
+
+impl Emitter for GithubEmitter {
+    fn emit(
+        &mut self,
+        writer: &mut dyn Write,
+        reports: &[CommitReport],
+    ) -> std::io::Result<()> {
+        for report in reports {
+            for diagnostic in report.diagnostics.iter().filter(|diagnostic| !diagnostic.fixed)
+            {
+                let message = match &report.sha {
+                    Some(sha) => format!("Commit {sha}: {}", diagnostic.violation.message()),
+                    None => diagnostic.violation.message(),
+                };
+                writeln!(
+                    writer,
+                    "::{} title={}::{}",
+                    diagnostic.severity.as_display(),
+                    escape_property(&diagnostic.rule_id()),
+                    escape_data(&message)
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Escapes the message of the workflow command.
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escapes the property value of the workflow command.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use commitfmt_linter::Severity;
+
+    use super::*;
+    use crate::emitter::lint;
+
+    #[test]
+    fn test_emit() {
+        let mut fixed = lint(None, "feat: fixed.");
+        fixed.diagnostics[0].fixed = true;
+        let mut warning = lint(None, "feat: other.");
+        warning.diagnostics[0].severity = Severity::Warning;
+        let reports = vec![lint(Some("1a2b3c4"), "feat: description."), warning, fixed];
+
+        let mut output = Vec::new();
+        GithubEmitter.emit(&mut output, &reports).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "::error title=header.description-full-stop::Commit 1a2b3c4: Header description is ended with a full stop\n\
+             ::warning title=header.description-full-stop::Header description is ended with a full stop\n"
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape_data("100%\nnext\r"), "100%25%0Anext%0D");
+        assert_eq!(escape_property("a: b, c"), "a%3A b%2C c");
+    }
+}
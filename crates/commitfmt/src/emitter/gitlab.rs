@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::io::Write;
+
+use serde_json::{json, Value};
+
+use commitfmt_linter::Severity;
+
+use crate::emitter::Emitter;
+use crate::report::CommitReport;
+
+/// Path reported for messages that are not committed yet.
+const COMMIT_MESSAGE_PATH: &str = "COMMIT_EDITMSG";
+
+/// Emits unfixed violations as a GitLab Code Quality report.
+pub struct GitlabEmitter;
+
+impl Emitter for GitlabEmitter {
+    fn emit(
+        &mut self,
+        writer: &mut dyn Write,
+        reports: &[CommitReport],
+    ) -> std::io::Result<()> {
+        let mut issues: Vec<Value> = Vec::new();
+
+        for report in reports {
+            let path = report.sha.as_deref().unwrap_or(COMMIT_MESSAGE_PATH);
+            // The same rule can be violated several times in one message,
+            // but fingerprints must stay unique within the report.
+            let mut occurrences: HashMap<String, usize> = HashMap::new();
+
+            for diagnostic in report.diagnostics.iter().filter(|diagnostic| !diagnostic.fixed)
+            {
+                let check_name = diagnostic.rule_id();
+                let occurrence = occurrences.entry(check_name.clone()).or_default();
+                let fingerprint = match *occurrence {
+                    0 => format!("{path}:{check_name}"),
+                    n => format!("{path}:{check_name}:{n}"),
+                };
+                *occurrence += 1;
+
+                let line = diagnostic.violation.span().map_or(1, |span| span.start.line);
+                let severity = match diagnostic.severity {
+                    Severity::Error => "major",
+                    Severity::Warning => "minor",
+                };
+                issues.push(json!({
+                    "description": diagnostic.violation.message(),
+                    "check_name": check_name,
+                    "fingerprint": fingerprint,
+                    "severity": severity,
+                    "location": {
+                        "path": path,
+                        "lines": { "begin": line },
+                    },
+                }));
+            }
+        }
+
+        serde_json::to_writer_pretty(&mut *writer, &issues)?;
+        writeln!(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::emitter::lint;
+
+    #[test]
+    fn test_emit() {
+        let mut report = lint(Some("1a2b3c4"), "feat: description.");
+        let violation = lint(None, "feat: other.").diagnostics.remove(0);
+        report.diagnostics.push(violation);
+        let reports = vec![report, lint(None, "feat: ok")];
+
+        let mut output = Vec::new();
+        GitlabEmitter.emit(&mut output, &reports).unwrap();
+        let issues: Value = serde_json::from_slice(&output).unwrap();
+
+        assert_eq!(
+            issues,
+            json!([
+                {
+                    "description": "Header description is ended with a full stop",
+                    "check_name": "header.description-full-stop",
+                    "fingerprint": "1a2b3c4:header.description-full-stop",
+                    "severity": "major",
+                    "location": { "path": "1a2b3c4", "lines": { "begin": 1 } },
+                },
+                {
+                    "description": "Header description is ended with a full stop",
+                    "check_name": "header.description-full-stop",
+                    "fingerprint": "1a2b3c4:header.description-full-stop:1",
+                    "severity": "major",
+                    "location": { "path": "1a2b3c4", "lines": { "begin": 1 } },
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_emit_empty() {
+        let mut output = Vec::new();
+        GitlabEmitter.emit(&mut output, &[]).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "[]\n");
+    }
+}
@@ -0,0 +1,92 @@
+use std::io::Write;
+
+use serde_json::{json, Value};
+
+use crate::emitter::Emitter;
+use crate::report::{CommitReport, Diagnostic};
+
+/// Emits violations as a JSON array.
+pub struct JsonEmitter;
+
+impl Emitter for JsonEmitter {
+    fn emit(
+        &mut self,
+        writer: &mut dyn Write,
+        reports: &[CommitReport],
+    ) -> std::io::Result<()> {
+        let values: Vec<Value> = reports
+            .iter()
+            .flat_map(|report| {
+                report
+                    .diagnostics
+                    .iter()
+                    .map(|diagnostic| diagnostic_to_json(report, diagnostic))
+            })
+            .collect();
+
+        serde_json::to_writer_pretty(&mut *writer, &values)?;
+        writeln!(writer)
+    }
+}
+
+fn diagnostic_to_json(report: &CommitReport, diagnostic: &Diagnostic) -> Value {
+    let violation = diagnostic.violation.as_ref();
+    json!({
+        "sha": report.sha,
+        "author": report.author,
+        "rule": diagnostic.rule().as_display(),
+        "group": violation.group().as_display(),
+        "message": violation.message(),
+        "span": violation.span(),
+        "fix_mode": violation.fix_mode().as_display(),
+        "severity": diagnostic.severity.as_display(),
+        "fixed": diagnostic.fixed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::emitter::lint;
+
+    #[test]
+    fn test_emit() {
+        let mut reports = vec![
+            lint(Some("1a2b3c4"), "feat: description.")
+                .with_author("Dev <dev@example.com>".to_string()),
+            lint(None, "feat: ok"),
+        ];
+        reports[0].diagnostics[0].fixed = true;
+
+        let mut output = Vec::new();
+        JsonEmitter.emit(&mut output, &reports).unwrap();
+
+        let value: Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(
+            value,
+            json!([{
+                "sha": "1a2b3c4",
+                "author": "Dev <dev@example.com>",
+                "rule": "description-full-stop",
+                "group": "header",
+                "message": "Header description is ended with a full stop",
+                "span": {
+                    "start": { "line": 1, "column": 7, "offset": 6 },
+                    "end": { "line": 1, "column": 19, "offset": 18 },
+                },
+                "fix_mode": "safe",
+                "severity": "error",
+                "fixed": true,
+            }])
+        );
+    }
+
+    #[test]
+    fn test_emit_empty() {
+        let mut output = Vec::new();
+        JsonEmitter.emit(&mut output, &[lint(None, "feat: ok")]).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "[]\n");
+    }
+}
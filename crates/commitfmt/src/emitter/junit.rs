@@ -0,0 +1,127 @@
+use std::io::Write;
+
+use crate::emitter::Emitter;
+use crate::report::CommitReport;
+
+const SUITE_NAME: &str = "commitfmt";
+/// Test case name for messages that are not committed yet.
+const COMMIT_MESSAGE_NAME: &str = "commit message";
+
+/// Emits a `JUnit` XML report where every commit is a test case.
+pub struct JunitEmitter;
+
+impl Emitter for JunitEmitter {
+    fn emit(
+        &mut self,
+        writer: &mut dyn Write,
+        reports: &[CommitReport],
+    ) -> std::io::Result<()> {
+        let failures = reports.iter().filter(|report| report.problems_count() > 0).count();
+        let skipped = reports.iter().filter(|report| report.ignored).count();
+
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            writer,
+            r#"<testsuites name="{SUITE_NAME}" tests="{}" failures="{failures}" skipped="{skipped}">"#,
+            reports.len()
+        )?;
+        writeln!(
+            writer,
+            r#"  <testsuite name="{SUITE_NAME}" tests="{}" failures="{failures}" skipped="{skipped}">"#,
+            reports.len()
+        )?;
+
+        for report in reports {
+            let name = escape(report.sha.as_deref().unwrap_or(COMMIT_MESSAGE_NAME));
+            let testcase = format!(r#"    <testcase name="{name}" classname="{SUITE_NAME}""#);
+
+            if report.ignored {
+                writeln!(writer, "{testcase}>")?;
+                writeln!(writer, r#"      <skipped message="Commit is ignored"/>"#)?;
+                writeln!(writer, "    </testcase>")?;
+                continue;
+            }
+            if report.problems_count() == 0 {
+                writeln!(writer, "{testcase}/>")?;
+                continue;
+            }
+
+            writeln!(writer, "{testcase}>")?;
+            for diagnostic in
+                report.diagnostics.iter().filter(|diagnostic| diagnostic.is_problem())
+            {
+                let rule = escape(&diagnostic.rule_id());
+                let message = escape(&diagnostic.violation.message());
+                writeln!(
+                    writer,
+                    r#"      <failure type="{rule}" message="{message}">{rule}: {message}</failure>"#
+                )?;
+            }
+            writeln!(writer, "    </testcase>")?;
+        }
+
+        writeln!(writer, "  </testsuite>")?;
+        writeln!(writer, "</testsuites>")
+    }
+}
+
+/// Escapes the text for use in XML attributes and text nodes.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::emitter::lint;
+
+    #[test]
+    fn test_emit() {
+        let reports = vec![
+            lint(Some("1a2b3c4"), "feat: description."),
+            lint(Some("5d6e7f8"), "feat: ok"),
+            CommitReport::ignored(Some("9a0b1c2".to_string())),
+        ];
+
+        let mut output = Vec::new();
+        JunitEmitter.emit(&mut output, &reports).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="commitfmt" tests="3" failures="1" skipped="1">
+  <testsuite name="commitfmt" tests="3" failures="1" skipped="1">
+    <testcase name="1a2b3c4" classname="commitfmt">
+      <failure type="header.description-full-stop" message="Header description is ended with a full stop">header.description-full-stop: Header description is ended with a full stop</failure>
+    </testcase>
+    <testcase name="5d6e7f8" classname="commitfmt"/>
+    <testcase name="9a0b1c2" classname="commitfmt">
+      <skipped message="Commit is ignored"/>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape(r#"<a href="x">'&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&apos;&amp;&apos;&lt;/a&gt;"
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use std::io::Write;
+
+use clap::ValueEnum;
+
+use crate::report::CommitReport;
+
+mod github;
+mod gitlab;
+mod json;
+mod junit;
+mod sarif;
+
+pub use github::GithubEmitter;
+pub use gitlab::GitlabEmitter;
+pub use json::JsonEmitter;
+pub use junit::JunitEmitter;
+pub use sarif::SarifEmitter;
+
+/// Output format of the lint results.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Colored human-readable lines
+    #[default]
+    Text,
+    /// A single JSON document with all violations
+    Json,
+    /// SARIF 2.1.0 log for code scanning tools
+    Sarif,
+    /// GitHub Actions `::error` and `::warning` workflow commands
+    Github,
+    /// GitLab Code Quality report
+    GitlabCodequality,
+    /// `JUnit` XML report with a test case per commit
+    Junit,
+}
+
+impl OutputFormat {
+    /// Returns the emitter for machine-readable formats.
+    /// Text output is written by the logger, so it has no emitter.
+    pub fn emitter(self) -> Option<Box<dyn Emitter>> {
+        match self {
+            OutputFormat::Text => None,
+            OutputFormat::Json => Some(Box::new(JsonEmitter)),
+            OutputFormat::Sarif => Some(Box::new(SarifEmitter)),
+            OutputFormat::Github => Some(Box::new(GithubEmitter)),
+            OutputFormat::GitlabCodequality => Some(Box::new(GitlabEmitter)),
+            OutputFormat::Junit => Some(Box::new(JunitEmitter)),
+        }
+    }
+}
+
+/// Writes lint results of a run as a single document.
+pub trait Emitter {
+    fn emit(
+        &mut self,
+        writer: &mut dyn Write,
+        reports: &[CommitReport],
+    ) -> std::io::Result<()>;
+}
+
+/// Lints the message with the default settings, for emitter tests.
+#[cfg(test)]
+pub(crate) fn lint(sha: Option<&str>, input: &str) -> CommitReport {
+    use commitfmt_cc::Message;
+    use commitfmt_linter::rules::Settings;
+    use commitfmt_linter::{Check, RuleSet};
+
+    let settings = Settings::default();
+    let mut check = Check::new(&settings, RuleSet::default());
+    check.lint(&Message::parse(input, None, None));
+    CommitReport::new(sha.map(str::to_string), check.report)
+}
@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use serde_json::{json, Value};
+
+use crate::emitter::Emitter;
+use crate::report::{CommitReport, Diagnostic};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const TOOL_NAME: &str = "commitfmt";
+const TOOL_URI: &str = "https://github.com/mishamyrt/commitfmt";
+const RULES_DOCS_URI: &str =
+    "https://github.com/mishamyrt/commitfmt/blob/main/crates/commitfmt-linter/docs";
+
+/// Section of the rule documentation that describes the rule.
+const EXPLAIN_SUMMARY_TITLE: &str = "## What it does";
+
+/// Emits unfixed violations as a SARIF 2.1.0 log.
+pub struct SarifEmitter;
+
+impl Emitter for SarifEmitter {
+    fn emit(
+        &mut self,
+        writer: &mut dyn Write,
+        reports: &[CommitReport],
+    ) -> std::io::Result<()> {
+        let diagnostics = reports.iter().flat_map(|report| {
+            report
+                .diagnostics
+                .iter()
+                .filter(|diagnostic| !diagnostic.fixed)
+                .map(move |diagnostic| (report, diagnostic))
+        });
+
+        // Rules are sorted by id, so the run is stable between invocations.
+        let mut rules: BTreeMap<String, &Diagnostic> = BTreeMap::new();
+        for (_, diagnostic) in diagnostics.clone() {
+            rules.entry(diagnostic.rule_id()).or_insert(diagnostic);
+        }
+        let rule_indices: BTreeMap<&str, usize> =
+            rules.keys().enumerate().map(|(index, id)| (id.as_str(), index)).collect();
+
+        let results: Vec<Value> = diagnostics
+            .map(|(report, diagnostic)| {
+                let id = diagnostic.rule_id();
+                result_to_json(report, diagnostic, &id, rule_indices[id.as_str()])
+            })
+            .collect();
+        let rules: Vec<Value> =
+            rules.iter().map(|(id, diagnostic)| rule_to_json(id, diagnostic)).collect();
+
+        let log = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": TOOL_NAME,
+                        "informationUri": TOOL_URI,
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    },
+                },
+                "results": results,
+            }],
+        });
+
+        serde_json::to_writer_pretty(&mut *writer, &log)?;
+        writeln!(writer)
+    }
+}
+
+fn rule_to_json(id: &str, diagnostic: &Diagnostic) -> Value {
+    let group = diagnostic.violation.group().as_display();
+    let name = diagnostic.rule().as_display();
+    let explanation = diagnostic.violation.explain().unwrap_or_default();
+
+    let mut rule = json!({
+        "id": id,
+        "name": name,
+        "helpUri": format!("{RULES_DOCS_URI}/{group}/{name}.md"),
+        "help": { "text": explanation, "markdown": explanation },
+        "properties": { "fixMode": diagnostic.violation.fix_mode().as_display() },
+    });
+    if let Some(summary) = explain_summary(explanation) {
+        rule["shortDescription"] = json!({ "text": summary });
+    }
+
+    rule
+}
+
+fn result_to_json(
+    report: &CommitReport,
+    diagnostic: &Diagnostic,
+    rule_id: &str,
+    rule_index: usize,
+) -> Value {
+    let mut result = json!({
+        "ruleId": rule_id,
+        "ruleIndex": rule_index,
+        "level": diagnostic.severity.as_display(),
+        "message": { "text": diagnostic.violation.message() },
+    });
+    if let Some(sha) = &report.sha {
+        result["locations"] = json!([{
+            "logicalLocations": [{ "name": sha, "kind": "commit" }],
+        }]);
+    }
+
+    result
+}
+
+/// Extracts the first paragraph of the "What it does" section.
+fn explain_summary(explanation: &str) -> Option<String> {
+    let (_, section) = explanation.split_once(EXPLAIN_SUMMARY_TITLE)?;
+    let summary: Vec<&str> = section
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+
+    if summary.is_empty() {
+        return None;
+    }
+    Some(summary.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::emitter::lint;
+
+    #[test]
+    fn test_emit() {
+        let reports = vec![
+            lint(Some("1a2b3c4"), "feat: description."),
+            lint(Some("5d6e7f8"), "feat: other."),
+            lint(None, "feat: ok"),
+        ];
+
+        let mut output = Vec::new();
+        SarifEmitter.emit(&mut output, &reports).unwrap();
+        let log: Value = serde_json::from_slice(&output).unwrap();
+
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "commitfmt");
+
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0]["id"], "header.description-full-stop");
+        assert_eq!(
+            rules[0]["shortDescription"]["text"],
+            "Checks for header not ending with full stop"
+        );
+        assert!(rules[0]["helpUri"]
+            .as_str()
+            .unwrap()
+            .ends_with("/docs/header/description-full-stop.md"));
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1]["ruleIndex"], 0);
+        assert_eq!(results[1]["locations"][0]["logicalLocations"][0]["name"], "5d6e7f8");
+    }
+
+    #[test]
+    fn test_emit_skips_fixed() {
+        let mut reports = vec![lint(None, "feat: description.")];
+        reports[0].diagnostics[0].fixed = true;
+
+        let mut output = Vec::new();
+        SarifEmitter.emit(&mut output, &reports).unwrap();
+        let log: Value = serde_json::from_slice(&output).unwrap();
+
+        assert_eq!(log["runs"][0]["results"], json!([]));
+        assert_eq!(log["runs"][0]["tool"]["driver"]["rules"], json!([]));
+    }
+
+    #[test]
+    fn test_explain_summary() {
+        let explanation =
+            "## What it does\nChecks the thing\nin two lines\n\n## Why is this bad?\nIt is";
+        assert_eq!(
+            explain_summary(explanation),
+            Some("Checks the thing in two lines".to_string())
+        );
+        assert_eq!(explain_summary("## Example\n"), None);
+    }
+}
@@ -0,0 +1,269 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+
+use commitfmt_git::{HookType, Repository};
+
+use crate::{Error, Result};
+
+/// Marker line that identifies hooks written by commitfmt.
+const HOOK_MARKER: &str = "# Installed by commitfmt";
+/// Suffix of the hook that existed before the installation.
+const BACKUP_SUFFIX: &str = ".pre-commitfmt";
+
+/// Git hook that runs commitfmt.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Hook {
+    /// Formats the message before the editor is opened
+    #[default]
+    PrepareCommitMsg,
+    /// Formats the message after it is edited
+    CommitMsg,
+    /// Formats the message of a patch applied with `git am`
+    ApplypatchMsg,
+}
+
+impl Hook {
+    fn hook_type(self) -> HookType {
+        match self {
+            Hook::PrepareCommitMsg => HookType::PrepareCommitMsg,
+            Hook::CommitMsg => HookType::CommitMsg,
+            Hook::ApplypatchMsg => HookType::ApplyPatchMsg,
+        }
+    }
+}
+
+/// Result of the hook installation.
+#[derive(Debug, PartialEq, Eq)]
+pub enum InstallStatus {
+    Installed(PathBuf),
+    /// The existing hook was kept and is run before commitfmt.
+    Chained(PathBuf),
+    AlreadyInstalled(PathBuf),
+}
+
+/// Result of the hook removal.
+#[derive(Debug, PartialEq, Eq)]
+pub enum UninstallStatus {
+    Removed(PathBuf),
+    /// The hook that existed before the installation is put back.
+    Restored(PathBuf),
+    NotInstalled(PathBuf),
+}
+
+/// Writes the hook script that runs `command`.
+///
+/// The command is split into words on whitespace, unless it's a path to an existing file.
+/// Each word is quoted, so the shell doesn't expand it.
+/// The hooks directory is resolved by git, so `core.hooksPath` is respected.
+/// An existing hook is kept next to the new one and is run first.
+pub fn install_hook(repo: &Repository, hook: Hook, command: &str) -> Result<InstallStatus> {
+    let path = repo.hook_path(hook.hook_type())?;
+    let backup_path = backup_path(&path);
+
+    if path.exists() {
+        if is_commitfmt_hook(&path)? {
+            return Ok(InstallStatus::AlreadyInstalled(path));
+        }
+        if backup_path.exists() {
+            return Err(Error::HookBackupExists(backup_path));
+        }
+        fs::rename(&path, &backup_path)?;
+    } else if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let chained = backup_path.exists();
+    fs::write(&path, hook_script(hook, command, chained))?;
+    make_executable(&path)?;
+
+    if chained {
+        Ok(InstallStatus::Chained(path))
+    } else {
+        Ok(InstallStatus::Installed(path))
+    }
+}
+
+/// Removes the hook written by [`install_hook`] and restores the previous one.
+pub fn uninstall_hook(repo: &Repository, hook: Hook) -> Result<UninstallStatus> {
+    let path = repo.hook_path(hook.hook_type())?;
+    if !path.exists() {
+        return Ok(UninstallStatus::NotInstalled(path));
+    }
+    if !is_commitfmt_hook(&path)? {
+        return Err(Error::ForeignHook(path));
+    }
+
+    fs::remove_file(&path)?;
+
+    let backup_path = backup_path(&path);
+    if backup_path.exists() {
+        fs::rename(&backup_path, &path)?;
+        return Ok(UninstallStatus::Restored(path));
+    }
+
+    Ok(UninstallStatus::Removed(path))
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(BACKUP_SUFFIX);
+    path.with_file_name(name)
+}
+
+fn is_commitfmt_hook(path: &Path) -> Result<bool> {
+    let content = fs::read(path)?;
+    Ok(String::from_utf8_lossy(&content).lines().any(|line| line == HOOK_MARKER))
+}
+
+fn hook_script(hook: Hook, command: &str, chained: bool) -> String {
+    let previous = if chained {
+        let name = hook.hook_type();
+        format!(
+            r#"previous="$(dirname "$0")/{name}{BACKUP_SUFFIX}"
+if [ -x "$previous" ]; then
+    "$previous" "$@" || exit $?
+fi
+
+"#
+        )
+    } else {
+        String::new()
+    };
+
+    let command = command_line(command);
+    format!("#!/bin/sh\n{HOOK_MARKER}\n\n{previous}exec {command} \"$@\"\n")
+}
+
+/// Returns the command as shell words
+fn command_line(command: &str) -> String {
+    if Path::new(command).is_file() {
+        return shell_quote(command);
+    }
+    command.split_whitespace().map(shell_quote).collect::<Vec<_>>().join(" ")
+}
+
+/// Quotes the word if it contains characters that are special to the shell
+fn shell_quote(word: &str) -> String {
+    let is_plain = |c: char| c.is_ascii_alphanumeric() || "@%+=:,./_-".contains(c);
+    if !word.is_empty() && word.chars().all(is_plain) {
+        return word.to_string();
+    }
+    format!("'{}'", word.replace('\'', r"'\''"))
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use commitfmt_git::testing::TestBed;
+
+    use super::*;
+
+    #[test]
+    fn test_install_and_uninstall() {
+        let test_bed = TestBed::empty().unwrap();
+        let path = test_bed.path().join(".git/hooks/prepare-commit-msg");
+
+        let status =
+            install_hook(&test_bed.repo, Hook::PrepareCommitMsg, "commitfmt").unwrap();
+        assert_eq!(status, InstallStatus::Installed(path.clone()));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "#!/bin/sh\n# Installed by commitfmt\n\nexec commitfmt \"$@\"\n"
+        );
+
+        let status =
+            install_hook(&test_bed.repo, Hook::PrepareCommitMsg, "commitfmt").unwrap();
+        assert_eq!(status, InstallStatus::AlreadyInstalled(path.clone()));
+
+        let status = uninstall_hook(&test_bed.repo, Hook::PrepareCommitMsg).unwrap();
+        assert_eq!(status, UninstallStatus::Removed(path.clone()));
+        assert!(!path.exists());
+
+        let status = uninstall_hook(&test_bed.repo, Hook::PrepareCommitMsg).unwrap();
+        assert_eq!(status, UninstallStatus::NotInstalled(path));
+    }
+
+    #[test]
+    fn test_install_chains_existing_hook() {
+        let test_bed = TestBed::empty().unwrap();
+        let path = test_bed.path().join(".git/hooks/commit-msg");
+        let existing = "#!/bin/sh\necho existing\n";
+        fs::write(&path, existing).unwrap();
+
+        let status = install_hook(&test_bed.repo, Hook::CommitMsg, "pnpm commitfmt").unwrap();
+        assert_eq!(status, InstallStatus::Chained(path.clone()));
+        assert_eq!(fs::read_to_string(backup_path(&path)).unwrap(), existing);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            r#"#!/bin/sh
+# Installed by commitfmt
+
+previous="$(dirname "$0")/commit-msg.pre-commitfmt"
+if [ -x "$previous" ]; then
+    "$previous" "$@" || exit $?
+fi
+
+exec pnpm commitfmt "$@"
+"#
+        );
+
+        let status = uninstall_hook(&test_bed.repo, Hook::CommitMsg).unwrap();
+        assert_eq!(status, UninstallStatus::Restored(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), existing);
+        assert!(!backup_path(&path).exists());
+    }
+
+    #[test]
+    fn test_command_line() {
+        assert_eq!(command_line("pnpm  commitfmt"), "pnpm commitfmt");
+        assert_eq!(command_line("/opt/bin/commitfmt --lint"), "/opt/bin/commitfmt --lint");
+        assert_eq!(command_line("commitfmt $(rm -rf ~);"), "commitfmt '$(rm' -rf '~);'");
+        assert_eq!(command_line("it's"), r"'it'\''s'");
+
+        let test_bed = TestBed::empty().unwrap();
+        let path = test_bed.path().join("my tools/commitfmt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "").unwrap();
+        let path = path.to_str().unwrap();
+        assert_eq!(command_line(path), format!("'{path}'"));
+    }
+
+    #[test]
+    fn test_install_respects_hooks_path() {
+        let test_bed = TestBed::empty().unwrap();
+        let mut config = std::process::Command::new("git");
+        config.args(["config", "core.hooksPath", "custom-hooks"]).current_dir(test_bed.path());
+        assert!(config.status().unwrap().success());
+
+        let status = install_hook(&test_bed.repo, Hook::ApplypatchMsg, "commitfmt").unwrap();
+        let InstallStatus::Installed(path) = status else {
+            panic!("Unexpected status: {status:?}");
+        };
+        assert!(path.ends_with("custom-hooks/applypatch-msg"));
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn test_uninstall_foreign_hook() {
+        let test_bed = TestBed::empty().unwrap();
+        let path = test_bed.path().join(".git/hooks/prepare-commit-msg");
+        fs::write(&path, "#!/bin/sh\necho foreign\n").unwrap();
+
+        let result = uninstall_hook(&test_bed.repo, Hook::PrepareCommitMsg);
+        assert!(matches!(result, Err(Error::ForeignHook(_))));
+        assert!(path.exists());
+    }
+}
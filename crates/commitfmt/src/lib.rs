@@ -1,11 +1,15 @@
+pub mod baseline;
 pub(crate) mod logging;
 pub mod testing;
 
 pub use commitfmt::Commitfmt;
 pub(crate) mod commitfmt;
-pub mod ignore;
+pub mod config;
+pub mod emitter;
+pub mod hook;
+pub mod report;
 
-pub use logging::setup_logger;
+pub use logging::{pluralize, setup_logger};
 
 use thiserror::Error;
 
@@ -33,8 +37,20 @@ pub enum Error {
     #[error("Failed to open configuration file: {0}")]
     OpenConfig(#[from] commitfmt_workspace::Error),
 
+    #[error("Hook {0} was not installed by commitfmt")]
+    ForeignHook(std::path::PathBuf),
+
+    #[error("Unable to keep the existing hook, {0} already exists")]
+    HookBackupExists(std::path::PathBuf),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("The repository is in a detached HEAD state")]
     DetachedHead,
+
+    #[error("Invalid baseline entry on line {0}, expected `<full sha> <group.rule>`")]
+    InvalidBaseline(usize),
 }
 
 /// Application result.
@@ -30,7 +30,8 @@ macro_rules! print_warning {
     }
 }
 
-pub(crate) fn pluralize(count: usize, singular: &str, plural: &str) -> String {
+/// Returns the singular or plural form of the word for the count
+pub fn pluralize(count: usize, singular: &str, plural: &str) -> String {
     if count == 1 {
         singular.to_string()
     } else {
@@ -1,20 +1,28 @@
-use clap::{CommandFactory, Parser};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 
 use colored::Colorize;
 use std::{
-    io::{IsTerminal, Read},
+    io::{IsTerminal, Read, Write},
+    path::{Path, PathBuf},
     process,
 };
 
-use commitfmt::ignore::is_ignored_message;
+use commitfmt::config::{effective_config, render_config, ConfigFormat};
+use commitfmt::emitter::{Emitter, OutputFormat};
+use commitfmt::hook::{install_hook, uninstall_hook, Hook, InstallStatus, UninstallStatus};
+use commitfmt::report::{problems_count, CommitReport};
 use commitfmt::{
-    print_debug, print_error, print_info, print_warning, setup_logger, Commitfmt,
+    pluralize, print_debug, print_error, print_info, print_warning, setup_logger, Commitfmt,
 };
-use commitfmt_git::Repository;
+use commitfmt_git::{GitError, Repository};
+use commitfmt_linter::{Rule, RuleSet};
+use commitfmt_workspace::{check_config, rule_setting_type};
 
 /// Input source for the commit message.
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 enum InputSource {
+    /// The commit message is read from the file passed by git to the hook.
+    File(PathBuf),
     /// The commit message is read from stdin.
     Stdin,
     /// The commit message is read from the commit edit message (e.g. `COMMIT_EDITMSG`).
@@ -23,16 +31,32 @@ enum InputSource {
     None,
 }
 
+/// Source of the commit message passed by git to the `prepare-commit-msg` hook.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, ValueEnum)]
+enum MessageSource {
+    /// The message is given with `-m` or `-F`
+    Message,
+    /// The message is a template from `-t` or `commit.template`
+    Template,
+    /// The commit is a merge or `.git/MERGE_MSG` exists
+    Merge,
+    /// `.git/SQUASH_MSG` exists
+    Squash,
+    /// The message is taken from an existing commit with `-c`, `-C` or `--amend`
+    Commit,
+}
+
 /// Utility to add ticket id to commit message
 #[derive(Parser)]
-#[command(version, about, long_about = None)]
+#[command(version, about, long_about = None, args_conflicts_with_subcommands = true)]
+#[allow(clippy::struct_excessive_bools)]
 struct Cli {
     /// Turn debugging information on
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     pub verbose: bool,
 
     /// Disable colored output
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub no_color: bool,
 
     /// The lower boundary of the commit range to be checked.
@@ -45,9 +69,316 @@ struct Cli {
     #[arg(long)]
     pub to: Option<String>,
 
+    /// Follow only the first parent of merge commits when checking a range,
+    /// so only the mainline history is checked
+    #[arg(long)]
+    pub first_parent: bool,
+
     /// Check the message and return an error if any problem is found
     #[arg(short, long)]
     pub lint: bool,
+
+    /// Fail on warnings too, as if all rules were errors
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Normalize the whole message instead of editing only the parts with fixed problems
+    #[arg(long, conflicts_with = "lint")]
+    pub normalize: bool,
+
+    /// Output format of the found problems
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub output_format: OutputFormat,
+
+    /// File with the commit message, passed by git to the hook
+    pub msg_file: Option<PathBuf>,
+
+    /// Source of the commit message, passed by git to the `prepare-commit-msg` hook
+    #[arg(value_enum, requires = "msg_file")]
+    pub source: Option<MessageSource>,
+
+    /// Sha of the commit the message is taken from, passed with the `commit` source
+    #[arg(requires = "source")]
+    pub sha: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Install a git hook that runs commitfmt.
+    /// An existing hook is kept and run before commitfmt
+    Install {
+        /// Hook to install
+        #[arg(long, value_enum, default_value_t = Hook::PrepareCommitMsg)]
+        hook: Hook,
+
+        /// Command that runs commitfmt, e.g. `pnpm commitfmt`
+        #[arg(long, default_value = "commitfmt")]
+        command: String,
+    },
+    /// Remove the git hook installed by commitfmt and restore the previous one
+    Uninstall {
+        /// Hook to remove
+        #[arg(long, value_enum, default_value_t = Hook::PrepareCommitMsg)]
+        hook: Hook,
+    },
+    /// Manage the baseline of violations that are not reported by range linting
+    Baseline {
+        #[command(subcommand)]
+        command: BaselineCommand,
+    },
+    /// List all lint rules with their fix mode, default state and setting type
+    Rules,
+    /// Print the documentation of the lint rule
+    Explain {
+        /// Rule id in the `group.rule` form, e.g. `header.type-enum`
+        rule: String,
+    },
+    /// Inspect the effective configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+    /// Validate the config files and print the effective rules and additional footers.
+    /// Exits with an error if any problem is found
+    CheckConfig,
+}
+
+#[derive(Subcommand)]
+enum BaselineCommand {
+    /// Record the violations of the commit range to the baseline file.
+    /// The existing baseline is replaced
+    Write {
+        /// The lower boundary of the commit range
+        #[arg(long)]
+        from: String,
+
+        /// The upper boundary of the commit range
+        #[arg(long, default_value = "HEAD")]
+        to: String,
+
+        /// Follow only the first parent of merge commits
+        #[arg(long)]
+        first_parent: bool,
+    },
+    /// Remove the entries of commits that are not reachable anymore
+    Prune,
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Print the resolved configuration with the origin of each value:
+    /// default, parent config, child config or git config
+    Show {
+        /// Output format of the configuration
+        #[arg(long, value_enum, default_value_t = ConfigFormat::Toml)]
+        format: ConfigFormat,
+    },
+}
+
+/// Opens the repository of the directory and prints the error if it's not found.
+fn open_repository(cwd: &Path) -> Option<Repository> {
+    match Repository::open(cwd) {
+        Ok(repo) => Some(repo),
+        Err(err) => {
+            print_error!("{err}");
+            None
+        }
+    }
+}
+
+/// Runs the subcommand and returns the exit code.
+fn run_command(command: Command, cwd: &Path) -> process::ExitCode {
+    match command {
+        Command::Install { hook, command } => {
+            let Some(repo) = open_repository(cwd) else {
+                return process::ExitCode::FAILURE;
+            };
+            match install_hook(&repo, hook, &command) {
+                Ok(InstallStatus::Installed(path)) => {
+                    print_info!("Installed hook {}", path.display());
+                }
+                Ok(InstallStatus::Chained(path)) => {
+                    print_info!(
+                        "Installed hook {}, the existing hook is run first",
+                        path.display()
+                    );
+                }
+                Ok(InstallStatus::AlreadyInstalled(path)) => {
+                    print_info!("Hook {} is already installed", path.display());
+                }
+                Err(err) => {
+                    print_error!("{err}");
+                    return process::ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Uninstall { hook } => {
+            let Some(repo) = open_repository(cwd) else {
+                return process::ExitCode::FAILURE;
+            };
+            match uninstall_hook(&repo, hook) {
+                Ok(UninstallStatus::Removed(path)) => {
+                    print_info!("Removed hook {}", path.display());
+                }
+                Ok(UninstallStatus::Restored(path)) => {
+                    print_info!(
+                        "Removed hook {}, the previous hook is restored",
+                        path.display()
+                    );
+                }
+                Ok(UninstallStatus::NotInstalled(path)) => {
+                    print_info!("Hook {} is not installed", path.display());
+                }
+                Err(err) => {
+                    print_error!("{err}");
+                    return process::ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Baseline { command } => {
+            if let Err(err) = run_baseline_command(command, cwd) {
+                print_error!("{err}");
+                return process::ExitCode::FAILURE;
+            }
+        }
+        Command::Rules => print_rules(),
+        Command::Explain { rule } => {
+            let Some(rule) = Rule::from_id(&rule) else {
+                print_error!("Unknown rule: {rule}. Run `commitfmt rules` to list the rules");
+                return process::ExitCode::FAILURE;
+            };
+            print_info!("# {}\n\n{}", rule.id(), rule.explanation().trim_end());
+        }
+        Command::Config { command: ConfigCommand::Show { format } } => {
+            let config = match effective_config(cwd) {
+                Ok(config) => config,
+                Err(err) => {
+                    print_error!("{err}");
+                    return process::ExitCode::FAILURE;
+                }
+            };
+            let output = render_config(&config, format);
+            if let Err(err) = std::io::stdout().lock().write_all(output.as_bytes()) {
+                print_error!("Failed to write config: {err}");
+                return process::ExitCode::FAILURE;
+            }
+        }
+        Command::CheckConfig => return run_check_config(cwd),
+    }
+
+    process::ExitCode::SUCCESS
+}
+
+/// Prints the table of all lint rules.
+fn print_rules() {
+    let default_rules = RuleSet::default();
+    let rows: Vec<[String; 4]> = Rule::all()
+        .map(|rule| {
+            let default = if default_rules.contains(rule) { "on" } else { "off" };
+            [
+                rule.id(),
+                rule.fix_mode().as_display().to_string(),
+                default.to_string(),
+                rule_setting_type(rule).to_string(),
+            ]
+        })
+        .collect();
+
+    let header = ["Rule", "Fix", "Default", "Setting"].map(str::to_string);
+    let mut widths = header.each_ref().map(String::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(&rows) {
+        let [id, fix_mode, default, setting] = row;
+        let [id_width, fix_width, default_width, _] = widths;
+        print_info!(
+            "{id:id_width$}  {fix_mode:fix_width$}  {default:default_width$}  {setting}"
+        );
+    }
+}
+
+/// Validates the config of the directory and prints the effective settings.
+fn run_check_config(cwd: &Path) -> process::ExitCode {
+    let check = check_config(cwd);
+    for problem in &check.problems {
+        print_error!("{problem}");
+    }
+    let Some(settings) = check.settings else {
+        let count = check.problems.len();
+        print_error!("Found {count} {} in config", pluralize(count, "problem", "problems"));
+        return process::ExitCode::FAILURE;
+    };
+
+    if check.files.is_empty() {
+        print_info!("No config file found, using the default settings");
+    }
+    for path in &check.files {
+        print_info!("Config file: {}", path.display());
+    }
+
+    print_info!("\nRules:");
+    print_rule_set(settings.rules.set, settings.rules.warnings);
+    for type_override in &settings.rules.overrides {
+        print_info!("\nRules for '{}' commits:", type_override.kind);
+        print_rule_set(type_override.set, type_override.warnings);
+    }
+
+    let footers = settings.footers.borrow();
+    if !footers.is_empty() {
+        print_info!("\nAdditional footers:");
+    }
+    for footer in footers.iter() {
+        let mut options = vec![format!("on conflict: {}", footer.on_conflict.as_display())];
+        if let Some(pattern) = &footer.branch_pattern {
+            options.push(format!("branch pattern: {pattern}"));
+        }
+        print_info!("  {}: {} ({})", footer.key, footer.value, options.join(", "));
+    }
+
+    process::ExitCode::SUCCESS
+}
+
+/// Prints the rules of the set, marking the ones reported as warnings.
+fn print_rule_set(set: RuleSet, warnings: RuleSet) {
+    if set.is_empty() {
+        print_info!("  none");
+    }
+    for rule in &set {
+        if warnings.contains(rule) {
+            print_info!("  {} (warning)", rule.id());
+        } else {
+            print_info!("  {}", rule.id());
+        }
+    }
+}
+
+/// Runs the baseline subcommand.
+fn run_baseline_command(command: BaselineCommand, cwd: &Path) -> commitfmt::Result<()> {
+    let mut fmt = Commitfmt::from_path(cwd)?;
+    let path = fmt.baseline_path();
+
+    match command {
+        BaselineCommand::Write { from, to, first_parent } => {
+            fmt.log_options.first_parent = first_parent;
+            let count = fmt.write_baseline((&from, &to))?;
+            let entries = pluralize(count, "entry", "entries");
+            print_info!("Recorded {count} {entries} to {}", path.display());
+        }
+        BaselineCommand::Prune => {
+            let count = fmt.prune_baseline()?;
+            let entries = pluralize(count, "entry", "entries");
+            print_info!("Removed {count} {entries} from {}", path.display());
+        }
+    }
+
+    Ok(())
 }
 
 /// Returns true if and only if stdin is believed to be readable.
@@ -86,10 +417,15 @@ fn is_readable() -> bool {
 /// Returns the input source for the commit message.
 ///
 /// Tries to determine the input source from the following sources:
+/// - message file argument
 /// - stdin
 /// - commit edit message
 /// - none
-fn get_source(repo: &Repository) -> InputSource {
+fn get_source(repo: &Repository, msg_file: Option<&Path>) -> InputSource {
+    if let Some(path) = msg_file {
+        return InputSource::File(path.to_path_buf());
+    }
+
     if is_readable() {
         return InputSource::Stdin;
     }
@@ -101,6 +437,35 @@ fn get_source(repo: &Repository) -> InputSource {
     InputSource::None
 }
 
+/// Writes the formatted message back to the file it was read from.
+fn write_message(
+    repo: &Repository,
+    source: &InputSource,
+    message: &str,
+) -> Result<(), GitError> {
+    match source {
+        InputSource::File(path) => Ok(std::fs::write(path, message)?),
+        InputSource::CommitEditMessage => repo.write_commit_message(message),
+        InputSource::Stdin | InputSource::None => {
+            Err(std::io::Error::other("the message is not read from a file").into())
+        }
+    }
+}
+
+/// Writes reports to stdout and returns the exit code based on the problems found.
+fn emit_reports(emitter: &mut dyn Emitter, reports: &[CommitReport]) -> process::ExitCode {
+    if let Err(err) = emitter.emit(&mut std::io::stdout().lock(), reports) {
+        print_error!("Failed to write report: {err}");
+        return process::ExitCode::FAILURE;
+    }
+
+    if problems_count(reports) > 0 {
+        process::ExitCode::FAILURE
+    } else {
+        process::ExitCode::SUCCESS
+    }
+}
+
 fn main() -> process::ExitCode {
     let cli = Cli::parse();
     setup_logger(cli.verbose, cli.no_color);
@@ -110,7 +475,11 @@ fn main() -> process::ExitCode {
         return process::ExitCode::FAILURE;
     };
 
-    let fmt = match Commitfmt::from_path(&cwd) {
+    if let Some(command) = cli.command {
+        return run_command(command, &cwd);
+    }
+
+    let mut fmt = match Commitfmt::from_path(&cwd) {
         Ok(fmt) => fmt,
         Err(err) => {
             print_error!("{err}");
@@ -123,13 +492,34 @@ fn main() -> process::ExitCode {
         return process::ExitCode::FAILURE;
     }
 
+    if cli.first_parent && cli.from.is_none() {
+        print_error!("--first-parent requires --from");
+        return process::ExitCode::FAILURE;
+    }
+    fmt.log_options.first_parent = cli.first_parent;
+    if cli.strict {
+        fmt.settings.rules.deny_warnings();
+    }
+
+    let mut emitter = cli.output_format.emitter();
+
     if let Some(from) = cli.from {
+        let to = cli.to.as_deref().unwrap_or("HEAD");
+
+        if let Some(emitter) = emitter.as_deref_mut() {
+            return match fmt.check_commit_range((&from, to)) {
+                Ok(reports) => emit_reports(emitter, &reports),
+                Err(err) => {
+                    print_error!("{err}");
+                    process::ExitCode::FAILURE
+                }
+            };
+        }
+
         if cli.lint {
             print_warning!("--lint is ignored when --from is set");
         }
 
-        let to = cli.to.as_deref().unwrap_or("HEAD");
-
         return match fmt.lint_commit_range((&from, to)) {
             Ok(()) => process::ExitCode::SUCCESS,
             Err(err) => {
@@ -139,10 +529,35 @@ fn main() -> process::ExitCode {
         };
     }
 
-    let source = get_source(&fmt.repo);
+    match cli.source {
+        Some(kind @ (MessageSource::Merge | MessageSource::Squash)) => {
+            if let Some(emitter) = emitter.as_deref_mut() {
+                return emit_reports(emitter, &[CommitReport::ignored(cli.sha.clone())]);
+            }
+            let name = kind.to_possible_value().expect("source is not skipped");
+            print_warning!("Skipping {} message", name.get_name());
+            return process::ExitCode::SUCCESS;
+        }
+        Some(MessageSource::Template | MessageSource::Message | MessageSource::Commit)
+        | None => {}
+    }
+
+    let source = get_source(&fmt.repo, cli.msg_file.as_deref());
     print_debug!("Input source: {source:?}");
 
-    let input = match source {
+    if emitter.is_some() && !cli.lint && source == InputSource::Stdin {
+        print_error!("--output-format can't be used when formatting stdin, use --lint");
+        return process::ExitCode::FAILURE;
+    }
+
+    let input = match &source {
+        InputSource::File(path) => match std::fs::read_to_string(path) {
+            Ok(msg) => msg,
+            Err(err) => {
+                print_error!("Failed to read {}: {err}", path.display());
+                return process::ExitCode::FAILURE;
+            }
+        },
         InputSource::Stdin => {
             let mut input = String::new();
             if let Err(err) = std::io::stdin().read_to_string(&mut input) {
@@ -166,11 +581,67 @@ fn main() -> process::ExitCode {
         }
     };
 
-    if is_ignored_message(&input) {
-        print_warning!("Skipping merge commit");
+    if cli.source == Some(MessageSource::Template) {
+        // The template is not written by the user yet, so only the footers are appended,
+        // the edited message is checked by the `commit-msg` hook
+        print_debug!("Skipping template message check");
+        if !cli.lint {
+            let output = match fmt.append_footer_edits(&input) {
+                Ok(output) => output,
+                Err(err) => {
+                    print_error!("{err}");
+                    return process::ExitCode::FAILURE;
+                }
+            };
+            if let Err(err) = write_message(&fmt.repo, &source, &output) {
+                print_error!("Failed to write commit message: {err}");
+                return process::ExitCode::FAILURE;
+            }
+        }
+        if let Some(emitter) = emitter.as_deref_mut() {
+            return emit_reports(emitter, &[CommitReport::ignored(None)]);
+        }
+        return process::ExitCode::SUCCESS;
+    }
+
+    if fmt.is_ignored_message(&input) {
+        if let Some(emitter) = emitter.as_deref_mut() {
+            return emit_reports(emitter, &[CommitReport::ignored(None)]);
+        }
+        print_warning!("Skipping ignored commit");
         return process::ExitCode::SUCCESS;
     }
 
+    if let Some(emitter) = emitter.as_deref_mut() {
+        if cli.lint {
+            return emit_reports(emitter, &[fmt.check_commit_message(&input)]);
+        }
+
+        let (fixed, report) = fmt.fix_commit_message(&input);
+        let has_problems = report.problems_count() > 0;
+        let exit_code = emit_reports(emitter, &[report]);
+        if has_problems {
+            return exit_code;
+        }
+        let output = if cli.normalize {
+            fmt.format_message(&fixed)
+        } else {
+            fmt.append_footer_edits(&fixed)
+        };
+        let output = match output {
+            Ok(output) => output,
+            Err(err) => {
+                print_error!("{err}");
+                return process::ExitCode::FAILURE;
+            }
+        };
+        if let Err(err) = write_message(&fmt.repo, &source, &output) {
+            print_error!("Failed to write commit message: {err}");
+            return process::ExitCode::FAILURE;
+        }
+        return exit_code;
+    }
+
     if cli.lint {
         return match fmt.lint_commit_message(&input) {
             Ok(()) => process::ExitCode::SUCCESS,
@@ -181,7 +652,12 @@ fn main() -> process::ExitCode {
         };
     }
 
-    let output = match fmt.format_commit_message(&input) {
+    let output = if cli.normalize {
+        fmt.normalize_commit_message(&input)
+    } else {
+        fmt.format_commit_message(&input)
+    };
+    let output = match output {
         Ok(output) => output,
         Err(err) => {
             print_error!("\n{err}");
@@ -189,19 +665,11 @@ fn main() -> process::ExitCode {
         }
     };
 
-    match source {
-        InputSource::Stdin => {
-            print_info!("{output}");
-        }
-        InputSource::CommitEditMessage => {
-            if let Err(err) = fmt.repo.write_commit_message(&output) {
-                print_error!("Failed to write commit message: {err}");
-                return process::ExitCode::FAILURE;
-            }
-        }
-        InputSource::None => {
-            unreachable!();
-        }
+    if source == InputSource::Stdin {
+        print_info!("{output}");
+    } else if let Err(err) = write_message(&fmt.repo, &source, &output) {
+        print_error!("Failed to write commit message: {err}");
+        return process::ExitCode::FAILURE;
     }
 
     process::ExitCode::SUCCESS
@@ -0,0 +1,92 @@
+use commitfmt_linter::report::Report;
+use commitfmt_linter::{Rule, Severity, Violation};
+
+/// A violation found in a commit message.
+pub struct Diagnostic {
+    pub violation: Box<dyn Violation>,
+    pub severity: Severity,
+    /// Whether the fix was applied to the message.
+    pub fixed: bool,
+}
+
+impl Diagnostic {
+    /// Creates a diagnostic for a violation that was not fixed.
+    pub fn new(violation: Box<dyn Violation>, severity: Severity) -> Self {
+        Self { violation, severity, fixed: false }
+    }
+
+    /// Returns true if the violation is not fixed and fails the check.
+    pub fn is_problem(&self) -> bool {
+        !self.fixed && self.severity == Severity::Error
+    }
+
+    /// Returns true if the violation is not fixed, but is only a warning.
+    pub fn is_warning(&self) -> bool {
+        !self.fixed && self.severity == Severity::Warning
+    }
+
+    /// Returns the rule that produced the violation.
+    pub fn rule(&self) -> Rule {
+        let Some(rule) = Rule::from_violation(self.violation.as_ref()) else {
+            panic!("Failed to get rule from violation");
+        };
+        rule
+    }
+
+    /// Returns the rule id in the `group.rule` form.
+    pub fn rule_id(&self) -> String {
+        let group = self.violation.group().as_display();
+        format!("{group}.{}", self.rule().as_display())
+    }
+}
+
+/// Lint result of a single commit message.
+#[derive(Default)]
+pub struct CommitReport {
+    /// Short sha of the commit. `None` if the message is not committed yet.
+    pub sha: Option<String>,
+    /// Author of the commit in the `Name <email>` form.
+    /// `None` if the message is not committed yet.
+    pub author: Option<String>,
+    /// Whether the message was skipped by the ignore rules.
+    pub ignored: bool,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl CommitReport {
+    /// Creates a report from the violations of the message.
+    pub fn new(sha: Option<String>, report: Report) -> Self {
+        let diagnostics = report
+            .into_violations()
+            .map(|(violation, severity)| Diagnostic::new(violation, severity))
+            .collect();
+        Self { sha, author: None, ignored: false, diagnostics }
+    }
+
+    /// Creates a report for the message skipped by the ignore rules.
+    pub fn ignored(sha: Option<String>) -> Self {
+        Self { sha, author: None, ignored: true, diagnostics: Vec::new() }
+    }
+
+    /// Sets the author of the commit.
+    #[must_use]
+    pub fn with_author(mut self, author: String) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Returns the number of errors that were not fixed.
+    pub fn problems_count(&self) -> usize {
+        self.diagnostics.iter().filter(|diagnostic| diagnostic.is_problem()).count()
+    }
+
+    /// Returns the number of warnings that were not fixed.
+    pub fn warnings_count(&self) -> usize {
+        self.diagnostics.iter().filter(|diagnostic| diagnostic.is_warning()).count()
+    }
+}
+
+/// Returns the number of unfixed errors in all reports.
+pub fn problems_count(reports: &[CommitReport]) -> usize {
+    reports.iter().map(CommitReport::problems_count).sum()
+}
@@ -0,0 +1,68 @@
+use commitfmt_git::testing::TestBed;
+use std::process::Command;
+
+fn check_config(test_bed: &TestBed) -> (bool, String) {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let output = Command::new(exe)
+        .args(["check-config", "--no-color"])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    (output.status.success(), String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+fn test_cli_check_config() {
+    let test_bed = TestBed::empty().unwrap();
+    std::fs::write(test_bed.path().join("base.toml"), "[lint.body]\nmax-line-length = 72\n")
+        .unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        r#"
+extends = "base.toml"
+
+[lint.header]
+scope-required = { level = "warn" }
+
+[lint.overrides.chore]
+skip = true
+
+[[additional-footers]]
+key = "Ticket-ID"
+value = "${{ TICKET_ID }}"
+branch-pattern = "(?:.*)/(?<TICKET_ID>[A-Z0-9-]+)"
+"#,
+    )
+    .unwrap();
+
+    let (success, stdout) = check_config(&test_bed);
+    assert!(success, "{stdout}");
+    assert!(stdout.contains("base.toml\n"));
+    assert!(stdout.contains("  body.max-line-length\n"));
+    assert!(stdout.contains("  header.scope-required (warning)\n"));
+    assert!(stdout.contains("Rules for 'chore' commits:\n  none\n"));
+    assert!(stdout.contains(
+        "  Ticket-ID: ${{ TICKET_ID }} (on conflict: skip, branch pattern: (?:.*)/(?<TICKET_ID>[A-Z0-9-]+))\n"
+    ));
+}
+
+#[test]
+fn test_cli_check_config_problems() {
+    let test_bed = TestBed::empty().unwrap();
+    std::fs::write(
+        test_bed.path().join("base.toml"),
+        "[lint.body]\nmax-line-length = \"72\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "extends = \"base.toml\"\n\n[lint.header]\nunknown = true\n",
+    )
+    .unwrap();
+
+    let (success, stdout) = check_config(&test_bed);
+    assert!(!success);
+    assert!(stdout.contains("base.toml:2:19: "), "{stdout}");
+    assert!(stdout.contains(".commitfmt.toml:4:1: Unknown rule: header → unknown\n"));
+    assert!(stdout.contains("Found 2 problems in config\n"));
+}
@@ -0,0 +1,45 @@
+use commitfmt_git::testing::TestBed;
+use std::process::Command;
+
+fn config_show(test_bed: &TestBed, format: &str) -> String {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let output = Command::new(exe)
+        .args(["config", "show", "--format", format])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_cli_config_show() {
+    let test_bed = TestBed::empty().unwrap();
+    Command::new("git")
+        .args(["config", "core.commentChar", ";"])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    std::fs::write(
+        test_bed.path().join("base.toml"),
+        "footer-separators = \":#\"\n\n[lint.body]\nmax-line-length = 72\n",
+    )
+    .unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "extends = \"base.toml\"\n\n[lint.header]\ntype-enum = [\"feat\", \"fix\"]\n",
+    )
+    .unwrap();
+
+    let output = config_show(&test_bed, "toml");
+    assert!(output.contains("footer-separators = \":#\" # parent config\n"), "{output}");
+    assert!(output.contains("comment-symbol = \";\" # git config\n"));
+    assert!(output.contains("\n[lint.body]\nmax-line-length = 72 # parent config\n"));
+    assert!(output.contains("type-enum = [\"feat\", \"fix\"] # child config\n"));
+    assert!(output.contains("description-full-stop = true # default\n"));
+
+    let output = config_show(&test_bed, "json");
+    let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(value["comment-symbol"]["origin"], "git config");
+    assert_eq!(value["lint"]["header"]["type-enum"]["value"][1], "fix");
+}
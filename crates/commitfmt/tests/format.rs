@@ -21,13 +21,32 @@ body
     let test_bed = TestBed::empty().unwrap();
     let app = Commitfmt::from_path(&test_bed.path()).unwrap();
 
-    let result = app.format_commit_message(input);
+    let result = app.normalize_commit_message(input);
 
     assert!(result.is_ok());
 
     assert_eq!(result.unwrap(), "feat(test): test\n\nbody");
 }
 
+#[test]
+fn test_format_keeps_untouched_text() {
+    let (_test_bed, app) = app_with_config(
+        r#"
+[[additional-footers]]
+key = "Reviewed-by"
+value = "John Doe"
+"#,
+    );
+
+    let input = "feat  (api): description.\nbody  text\n\nRefs:  #1\n# keep  this\n";
+    let result = app.format_commit_message(input).unwrap();
+
+    assert_eq!(
+        result,
+        "feat  (api): description\nbody  text\n\nRefs:  #1\nReviewed-by: John Doe\n# keep  this\n"
+    );
+}
+
 #[test]
 fn test_format_applies_safe_fix() {
     let test_bed = TestBed::empty().unwrap();
@@ -38,6 +57,27 @@ fn test_format_applies_safe_fix() {
     assert_eq!(result, "feat: description");
 }
 
+#[test]
+fn test_fix_keeps_unrelated_text() {
+    let (_test_bed, app) = app_with_config(
+        r#"
+breaking-key = "BREAKING-CHANGE"
+
+[lint.body]
+full-stop = true
+"#,
+    );
+
+    let input = "feat  (api): description.\n\n# keep  this\nbody  text\n\nBREAKING CHANGE:  x";
+    let (output, report) = app.fix_commit_message(input);
+
+    assert_eq!(report.diagnostics.len(), 3);
+    assert_eq!(
+        output,
+        "feat  (api)!: description\n\n# keep  this\nbody  text\n\nBREAKING-CHANGE:  x"
+    );
+}
+
 #[test]
 fn test_format_rejects_disabled_unsafe_fix() {
     let (_test_bed, app) = app_with_config(
@@ -132,6 +172,27 @@ value = "${{ TICKET_ID }}"
     assert_eq!(result, "feat: description");
 }
 
+#[test]
+fn test_format_skips_branch_footer_when_head_is_unreadable() {
+    let (test_bed, app) = app_with_config(
+        r#"
+[[additional-footers]]
+key = "Ticket-ID"
+branch-pattern = "^feature/(?<TICKET_ID>[A-Z0-9-]+)$"
+value = "${{ TICKET_ID }}"
+
+[[additional-footers]]
+key = "Reviewed-by"
+value = "John Doe"
+"#,
+    );
+    std::fs::remove_file(test_bed.repo.git_dir().join("HEAD")).unwrap();
+
+    let result = app.format_commit_message("feat: description").unwrap();
+
+    assert_eq!(result, "feat: description\n\nReviewed-by: John Doe");
+}
+
 #[test]
 fn test_cli_format_default_stdin() {
     let input = "
@@ -144,6 +205,7 @@ body
     let exe = env!("CARGO_BIN_EXE_commitfmt");
 
     let mut cmd = Command::new(exe);
+    cmd.arg("--normalize");
     cmd.stdin(pipe_from_string(input));
     cmd.current_dir(test_bed.path());
 
@@ -171,6 +233,7 @@ footer-key: value
     test_bed.repo.write_commit_message(input).unwrap();
 
     let mut cmd = Command::new(exe);
+    cmd.arg("--normalize");
     cmd.stdin(Stdio::null());
     cmd.current_dir(test_bed.path());
 
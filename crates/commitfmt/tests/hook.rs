@@ -5,6 +5,10 @@ use insta::assert_snapshot;
 use std::{fs::Permissions, os::unix::fs::PermissionsExt};
 
 fn write_hook(test_bed: &TestBed) {
+    write_hook_with_args(test_bed, "");
+}
+
+fn write_hook_with_args(test_bed: &TestBed, args: &str) {
     let exe: &str = env!("CARGO_BIN_EXE_commitfmt");
     let hook_path = test_bed.path().join(".git/hooks/prepare-commit-msg");
     let exe_path = {
@@ -15,7 +19,7 @@ fn write_hook(test_bed: &TestBed) {
         }
     };
 
-    std::fs::write(&hook_path, format!("#!/bin/sh\n\n{exe_path}")).unwrap();
+    std::fs::write(&hook_path, format!("#!/bin/sh\n\n{exe_path}{args}")).unwrap();
 
     #[cfg(unix)]
     std::fs::set_permissions(&hook_path, Permissions::from_mode(0o755)).unwrap();
@@ -28,12 +32,50 @@ fn test_hook_default() {
 
     write_hook(&test_bed);
 
+    repo.commit("feat(   test   ) : test.").unwrap();
+
+    let log = repo.get_log("HEAD~1", "HEAD").unwrap();
+    assert_eq!(log[0].message, "feat(   test   ) : test\n");
+}
+
+#[test]
+fn test_hook_normalize() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let repo = test_bed.repo.clone();
+
+    write_hook_with_args(&test_bed, " --normalize");
+
     repo.commit("feat(   test   ) : test").unwrap();
 
     let log = repo.get_log("HEAD~1", "HEAD").unwrap();
     assert_eq!(log[0].message, "feat(test): test\n");
 }
 
+#[test]
+fn test_hook_keeps_untouched_text() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let msg_file = test_bed.path().join("MSG");
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[[additional-footers]]\nkey = \"Reviewed-by\"\nvalue = \"John Doe\"\n",
+    )
+    .unwrap();
+    std::fs::write(&msg_file, "feat( api ): test.\nbody  text\n\n# Please enter the commit message\n#\tmodified:  file.txt\n").unwrap();
+
+    let output = std::process::Command::new(exe)
+        .arg(&msg_file)
+        .arg("message")
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(&msg_file).unwrap(),
+        "feat( api ): test\nbody  text\n\nReviewed-by: John Doe\n\n# Please enter the commit message\n#\tmodified:  file.txt\n"
+    );
+}
+
 #[test]
 fn test_hook_with_config_correct_message() {
     let test_bed = TestBed::with_default_history().unwrap();
@@ -47,7 +89,7 @@ type-enum = ["feat", "fix"]
 exists = ["Issue-ID"]
 "#;
     std::fs::write(test_bed.path().join(".commitfmt.toml"), config).unwrap();
-    write_hook(&test_bed);
+    write_hook_with_args(&test_bed, " --normalize");
 
     repo.commit("feat (  api   ) : test\nbody\n\nIssue-ID: 123").unwrap();
 
@@ -55,6 +97,23 @@ exists = ["Issue-ID"]
     assert_eq!(log[0].message, "feat(api): test\n\nbody\n\nIssue-ID: 123\n");
 }
 
+#[test]
+fn test_hook_with_breaking_key() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let repo = test_bed.repo.clone();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "breaking-key = \"BREAKING CHANGE\"\n",
+    )
+    .unwrap();
+    write_hook(&test_bed);
+
+    repo.commit("feat!: test\n\nBREAKING-CHANGE: new api").unwrap();
+
+    let log = repo.get_log("HEAD~1", "HEAD").unwrap();
+    assert_eq!(log[0].message, "feat!: test\n\nBREAKING CHANGE: new api\n");
+}
+
 #[test]
 fn test_hook_with_config_incorrect_message() {
     let test_bed = TestBed::with_default_history().unwrap();
@@ -118,6 +177,231 @@ value = "${{ TICKET_ID }}"
     let log = repo.get_log("HEAD~1", "HEAD").unwrap();
     assert_eq!(
         log[0].message,
-        "feat(test): test\n\nbody\n\nAuthored-by: John Doe\nTicket-ID: CFMT-123\n"
+        "feat(test): test\nbody\n\nAuthored-by: John Doe\nTicket-ID: CFMT-123\n"
+    );
+}
+
+#[test]
+fn test_hook_append_footers_during_rebase() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let repo = test_bed.repo.clone();
+    test_bed.switch_to_new("feature/CFMT-123").unwrap();
+    std::fs::write(test_bed.path().join("file.txt"), "content").unwrap();
+    let status = std::process::Command::new("git")
+        .args(["add", "file.txt"])
+        .current_dir(test_bed.path())
+        .status()
+        .unwrap();
+    assert!(status.success());
+    repo.commit("feat(test): test").unwrap();
+
+    let config = r#"
+[[additional-footers]]
+key = "Ticket-ID"
+branch-pattern = "^feature/(?<TICKET_ID>[A-Z0-9-]+)$"
+value = "${{ TICKET_ID }}"
+"#;
+    std::fs::write(test_bed.path().join(".commitfmt.toml"), config).unwrap();
+    write_hook(&test_bed);
+
+    let output = std::process::Command::new("git")
+        .args([
+            "-c",
+            "sequence.editor=perl -pi -e 's/^pick/reword/ if $. == 1'",
+            "rebase",
+            "-i",
+            "HEAD~1",
+        ])
+        .env("GIT_EDITOR", "true")
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let log = repo.get_log("HEAD~1", "HEAD").unwrap();
+    assert_eq!(log[0].message, "feat(test): test\n\nTicket-ID: CFMT-123\n");
+}
+
+#[test]
+fn test_hook_signed_off_by() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let repo = test_bed.repo.clone();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[lint.footer]\nsigned-off-by = \"author\"",
+    )
+    .unwrap();
+    write_hook(&test_bed);
+
+    repo.commit("feat: test").unwrap();
+
+    let log = repo.get_log("HEAD~1", "HEAD").unwrap();
+    assert_eq!(log[0].message, "feat: test\n\nSigned-off-by: Test User <test@example.com>\n");
+
+    let result = repo.commit("feat: test\n\nSigned-off-by: Jane Doe <jane@example.com>");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_hook_json_output_reports_applied_fixes() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+
+    test_bed.repo.write_commit_message("feat: description.").unwrap();
+
+    let output = std::process::Command::new(exe)
+        .arg("--output-format")
+        .arg("json")
+        .stdin(std::process::Stdio::null())
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let document: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(document[0]["rule"], "description-full-stop");
+    assert_eq!(document[0]["fixed"], true);
+    assert_eq!(test_bed.repo.read_commit_message().unwrap(), "feat: description");
+}
+
+#[test]
+fn test_cli_install_uninstall() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let repo = test_bed.repo.clone();
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let hook_command = if cfg!(windows) { exe.replace('\\', "/") } else { exe.to_string() };
+    let hook_command = format!("{hook_command} --normalize");
+
+    let output = std::process::Command::new(exe)
+        .args(["install", "--command", &hook_command])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout).unwrap().starts_with("Installed hook"));
+
+    repo.commit("feat(   test   ) : test").unwrap();
+    let log = repo.get_log("HEAD~1", "HEAD").unwrap();
+    assert_eq!(log[0].message, "feat(test): test\n");
+
+    let output = std::process::Command::new(exe)
+        .arg("uninstall")
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!test_bed.path().join(".git/hooks/prepare-commit-msg").exists());
+
+    repo.commit("feat(   test   ) : test").unwrap();
+    let log = repo.get_log("HEAD~1", "HEAD").unwrap();
+    assert_eq!(log[0].message, "feat(   test   ) : test\n");
+}
+
+#[test]
+fn test_hook_message_file_argument() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let msg_file = test_bed.path().join("MSG");
+    std::fs::write(&msg_file, "feat(   test   ) : test").unwrap();
+    test_bed.repo.write_commit_message("untouched").unwrap();
+
+    let output = std::process::Command::new(exe)
+        .arg("--normalize")
+        .arg(&msg_file)
+        .arg("message")
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(std::fs::read_to_string(&msg_file).unwrap(), "feat(test): test");
+    assert_eq!(test_bed.repo.read_commit_message().unwrap(), "untouched");
+}
+
+#[test]
+fn test_message_file_named_as_subcommand() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let msg_file = test_bed.path().join("rules");
+
+    for args in [&["--lint", "rules"][..], &["--normalize", "--", "rules"]] {
+        std::fs::write(&msg_file, "feat(   test   ) : test.").unwrap();
+
+        let output = std::process::Command::new(exe)
+            .args(args)
+            .current_dir(test_bed.path())
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(!stdout.contains("Setting"), "args: {args:?}");
+    }
+    assert_eq!(std::fs::read_to_string(&msg_file).unwrap(), "feat(test): test");
+}
+
+#[test]
+fn test_hook_skips_message_sources() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let msg_file = test_bed.path().join("MSG");
+
+    for source in ["merge", "squash", "template"] {
+        std::fs::write(&msg_file, "feat(   test   ) : test").unwrap();
+
+        let output = std::process::Command::new(exe)
+            .arg(&msg_file)
+            .arg(source)
+            .current_dir(test_bed.path())
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "source: {source}");
+        assert_eq!(std::fs::read_to_string(&msg_file).unwrap(), "feat(   test   ) : test");
+    }
+}
+
+#[test]
+fn test_hook_template_appends_footers() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let msg_file = test_bed.path().join("MSG");
+    test_bed.switch_to_new("feature/CFMT-123").unwrap();
+
+    let config = r#"
+[[additional-footers]]
+key = "Ticket-ID"
+branch-pattern = "^feature/(?<TICKET_ID>[A-Z0-9-]+)$"
+value = "${{ TICKET_ID }}"
+"#;
+    std::fs::write(test_bed.path().join(".commitfmt.toml"), config).unwrap();
+    std::fs::write(&msg_file, "feat(   test   ) : test.\n\n# Describe the change\n").unwrap();
+
+    let output = std::process::Command::new(exe)
+        .arg(&msg_file)
+        .arg("template")
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(&msg_file).unwrap(),
+        "feat(   test   ) : test.\n\nTicket-ID: CFMT-123\n\n# Describe the change\n"
     );
 }
+
+#[test]
+fn test_cli_install_commit_msg_hook() {
+    let test_bed = TestBed::with_default_history().unwrap();
+    let repo = test_bed.repo.clone();
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let hook_command = if cfg!(windows) { exe.replace('\\', "/") } else { exe.to_string() };
+    let hook_command = format!("{hook_command} --normalize");
+
+    let output = std::process::Command::new(exe)
+        .args(["install", "--hook", "commit-msg", "--command", &hook_command])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    repo.commit("feat(   test   ) : test").unwrap();
+    let log = repo.get_log("HEAD~1", "HEAD").unwrap();
+    assert_eq!(log[0].message, "feat(test): test\n");
+}
@@ -21,6 +21,54 @@ scope-required = true
     assert!(matches!(app.lint_commit_message("description"), Err(Error::Lint(2))));
 }
 
+#[test]
+fn test_lint_type_overrides() {
+    let test_bed = TestBed::empty().unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        r#"
+[lint.header]
+scope-required = true
+
+[lint.overrides.fix.footer]
+exists = ["Refs"]
+
+[lint.overrides.chore]
+skip = true
+"#,
+    )
+    .unwrap();
+    let app = Commitfmt::from_path(&test_bed.path()).unwrap();
+
+    assert!(app.lint_commit_message("feat(core): description").is_ok());
+    assert!(matches!(app.lint_commit_message("fix(core): description"), Err(Error::Lint(1))));
+    assert!(app.lint_commit_message("fix(core): description\n\nRefs: #1").is_ok());
+    assert!(matches!(app.lint_commit_message("fix: description"), Err(Error::Lint(2))));
+    assert!(app.lint_commit_message("chore: description").is_ok());
+}
+
+#[test]
+fn test_lint_warnings() {
+    let test_bed = TestBed::empty().unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[lint.header]\nscope-required = { level = \"warn\" }\ntype-required = true\n",
+    )
+    .unwrap();
+    let mut app = Commitfmt::from_path(&test_bed.path()).unwrap();
+
+    assert!(app.lint_commit_message("feat: description").is_ok());
+    assert!(matches!(app.lint_commit_message("description"), Err(Error::Lint(1))));
+    assert_eq!(app.format_commit_message("feat: description").unwrap(), "feat: description");
+
+    app.settings.rules.deny_warnings();
+    assert!(matches!(app.lint_commit_message("feat: description"), Err(Error::Lint(1))));
+    assert!(matches!(
+        app.format_commit_message("feat: description"),
+        Err(Error::Unfixable(1))
+    ));
+}
+
 #[test]
 fn test_cli_lint_valid_message_has_no_output() {
     let test_bed = TestBed::empty().unwrap();
@@ -117,6 +165,40 @@ description-max-length = 5
         assert!(output.status.success());
 
         let stdout = String::from_utf8(output.stdout).unwrap();
-        assert!(stdout.contains("Skipping merge commit"));
+        assert!(stdout.contains("Skipping ignored commit"));
     }
 }
+
+#[test]
+fn test_cli_lint_json_output() {
+    let test_bed = TestBed::empty().unwrap();
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+
+    let mut cmd = Command::new(exe);
+    cmd.arg("--lint").arg("--output-format").arg("json");
+    cmd.stdin(pipe_from_string("feat: description."));
+    cmd.current_dir(test_bed.path());
+
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let document: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(document[0]["sha"], serde_json::Value::Null);
+    assert_eq!(document[0]["rule"], "description-full-stop");
+    assert_eq!(document[0]["fix_mode"], "safe");
+    assert_eq!(document[0]["fixed"], false);
+}
+
+#[test]
+fn test_cli_format_json_output_rejects_stdin() {
+    let test_bed = TestBed::empty().unwrap();
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+
+    let mut cmd = Command::new(exe);
+    cmd.arg("--output-format").arg("json");
+    cmd.stdin(pipe_from_string("feat: description"));
+    cmd.current_dir(test_bed.path());
+
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+}
@@ -1,6 +1,7 @@
 use std::process::Command;
 
-use commitfmt::Commitfmt;
+use commitfmt::report::problems_count;
+use commitfmt::{Commitfmt, Error};
 use commitfmt_git::testing::TestBed;
 use insta::assert_snapshot;
 
@@ -78,15 +79,15 @@ description-min-length = 10
     testing_logger::validate(|captured_logs| {
         assert_eq!(captured_logs.len(), 10);
 
-        assert_snapshot!(captured_logs[1].body, @"- Header description is ended with a full stop [description-full-stop]");
-        assert_snapshot!(captured_logs[2].body, @"- Description is shorter than 10 characters [description-min-length]");
+        assert_snapshot!(captured_logs[1].body, @"- 1:13-1:18 Header description is ended with a full stop [description-full-stop]");
+        assert_snapshot!(captured_logs[2].body, @"- 1:13-1:18 Description is shorter than 10 characters [description-min-length]");
 
-        assert_snapshot!(captured_logs[4].body, @"- Scope is not allowed: tes [scope-enum]");
-        assert_snapshot!(captured_logs[5].body, @"- Description is shorter than 10 characters [description-min-length]");
+        assert_snapshot!(captured_logs[4].body, @"- 1:5-1:10 Scope is not allowed: tes [scope-enum]");
+        assert_snapshot!(captured_logs[5].body, @"- 1:12-1:16 Description is shorter than 10 characters [description-min-length]");
 
-        assert_snapshot!(captured_logs[7].body, @"- Type is not allowed: fea [type-enum]");
-        assert_snapshot!(captured_logs[8].body, @"- Header description is ended with a full stop [description-full-stop]");
-        assert_snapshot!(captured_logs[9].body, @"- Description is shorter than 10 characters [description-min-length]");
+        assert_snapshot!(captured_logs[7].body, @"- 1:1-1:4 Type is not allowed: fea [type-enum]");
+        assert_snapshot!(captured_logs[8].body, @"- 1:6-1:11 Header description is ended with a full stop [description-full-stop]");
+        assert_snapshot!(captured_logs[9].body, @"- 1:6-1:11 Description is shorter than 10 characters [description-min-length]");
     });
 }
 
@@ -175,9 +176,9 @@ description-max-length = 5
 
     assert_eq!(output_lines.len(), 5);
 
-    assert_snapshot!(output_lines[1], @"- Commit type is required [type-required]");
-    assert_snapshot!(output_lines[2], @"- Scope is required [scope-required]");
-    assert_snapshot!(output_lines[3], @"- Description is longer than 5 characters [description-max-length]");
+    assert_snapshot!(output_lines[1], @"- 1:1-1:15 Commit type is required [type-required]");
+    assert_snapshot!(output_lines[2], @"- 1:1-1:15 Scope is required [scope-required]");
+    assert_snapshot!(output_lines[3], @"- 1:1-1:15 Description is longer than 5 characters [description-max-length]");
 }
 
 #[test]
@@ -267,3 +268,425 @@ description-max-length = 5
     assert_eq!(output_lines.len(), 1);
     assert_snapshot!(output_lines[0], @"No problems found in 2 commits");
 }
+
+#[test]
+fn test_check_range_ignore_patterns() {
+    let config_data = r#"
+[ignore]
+patterns = ["^fixup! ", "^Merge"]
+
+[lint.header]
+type-enum = ["feat"]
+"#;
+
+    let test_bed =
+        TestBed::with_history(&["chore: initial", "fixup! feat: test", "Merge branch"])
+            .unwrap();
+    std::fs::write(test_bed.path().join(".commitfmt.toml"), config_data).unwrap();
+
+    let commitfmt = Commitfmt::from_path(&test_bed.path()).unwrap();
+    let reports = commitfmt.check_commit_range(("HEAD~2", "HEAD")).unwrap();
+    assert!(reports.iter().all(|report| report.ignored));
+
+    let config_data =
+        "[ignore]\npatterns = [\"^fixup! \"]\n\n[lint.header]\ntype-enum = [\"feat\"]\n";
+    std::fs::write(test_bed.path().join(".commitfmt.toml"), config_data).unwrap();
+
+    let commitfmt = Commitfmt::from_path(&test_bed.path()).unwrap();
+    let reports = commitfmt.check_commit_range(("HEAD~2", "HEAD")).unwrap();
+    // Default patterns are replaced, so the `Merge` message is checked
+    assert!(!reports[0].ignored);
+    assert!(reports[1].ignored);
+}
+
+#[test]
+fn test_check_range_ignore_initial_commit() {
+    let test_bed = TestBed::with_history(&["chore: initial", "Initial commit"]).unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[ignore]\npatterns = [\"^Initial commit$\"]\n\n[lint.header]\ntype-required = true\n",
+    )
+    .unwrap();
+
+    let commitfmt = Commitfmt::from_path(&test_bed.path()).unwrap();
+    let reports = commitfmt.check_commit_range(("HEAD~1", "HEAD")).unwrap();
+    assert_eq!(reports.len(), 1);
+    assert!(reports[0].ignored);
+    assert_eq!(problems_count(&reports), 0);
+}
+
+#[test]
+fn test_check_range_ignore_merges_by_parents() {
+    let test_bed = TestBed::with_history(&["chore: initial"]).unwrap();
+    test_bed.switch_to_new("feature").unwrap();
+    test_bed.repo.commit("feat: feature").unwrap();
+    test_bed.switch(TestBed::DEFAULT_BRANCH_NAME).unwrap();
+    test_bed.repo.commit("fix: main").unwrap();
+    test_bed.merge("feature", "not a conventional message").unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[lint.header]\ntype-required = true\n",
+    )
+    .unwrap();
+
+    let commitfmt = Commitfmt::from_path(&test_bed.path()).unwrap();
+    let reports = commitfmt.check_commit_range(("HEAD~1", "HEAD")).unwrap();
+    assert_eq!(reports.iter().filter(|report| report.ignored).count(), 1);
+    assert_eq!(problems_count(&reports), 0);
+
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[ignore]\nmerges = false\n\n[lint.header]\ntype-required = true\n",
+    )
+    .unwrap();
+
+    let commitfmt = Commitfmt::from_path(&test_bed.path()).unwrap();
+    let reports = commitfmt.check_commit_range(("HEAD~1", "HEAD")).unwrap();
+    assert!(reports.iter().all(|report| !report.ignored));
+    assert_eq!(problems_count(&reports), 1);
+}
+
+#[test]
+fn test_check_range_ignore_authors() {
+    let test_bed = TestBed::with_history(&["chore: initial", "bump deps"]).unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[ignore]\nauthors = [\"<test@example\\\\.com>$\"]\n\n[lint.header]\ntype-required = true\n",
+    )
+    .unwrap();
+
+    let commitfmt = Commitfmt::from_path(&test_bed.path()).unwrap();
+    let reports = commitfmt.check_commit_range(("HEAD~1", "HEAD")).unwrap();
+    assert!(reports.iter().all(|report| report.ignored));
+
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[ignore]\nauthors = [\"^dependabot\"]\n\n[lint.header]\ntype-required = true\n",
+    )
+    .unwrap();
+
+    let commitfmt = Commitfmt::from_path(&test_bed.path()).unwrap();
+    let reports = commitfmt.check_commit_range(("HEAD~1", "HEAD")).unwrap();
+    assert!(reports.iter().all(|report| !report.ignored));
+    assert_eq!(problems_count(&reports), 1);
+}
+
+#[test]
+fn test_cli_lint_first_parent() {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let test_bed = TestBed::with_history(&["chore: initial"]).unwrap();
+    test_bed.switch_to_new("feature").unwrap();
+    test_bed.repo.commit("not conventional").unwrap();
+    test_bed.switch(TestBed::DEFAULT_BRANCH_NAME).unwrap();
+    test_bed.repo.commit("fix: main").unwrap();
+    test_bed.merge("feature", "Merge branch 'feature'").unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[lint.header]\ntype-required = true\n",
+    )
+    .unwrap();
+
+    let output = Command::new(exe)
+        .args(["--from", "HEAD~2", "--first-parent"])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8(output.stdout).unwrap().trim_end(), @"No problems found in 2 commits");
+
+    let output = Command::new(exe)
+        .args(["--from", "HEAD~2"])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_cli_lint_range_warnings() {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let test_bed = TestBed::with_history(&["chore: initial", "fea: test"]).unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[lint.header]\ntype-enum = { level = \"warn\", value = [\"feat\", \"chore\"] }\n",
+    )
+    .unwrap();
+
+    let output = Command::new(exe)
+        .args(["--from", "HEAD~1"])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("warning: Type is not allowed: fea"));
+    assert!(stdout.contains("No errors found in 1 commit, 1 warning"));
+
+    let output = Command::new(exe)
+        .args(["--from", "HEAD~1", "--strict"])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_cli_first_parent_requires_from() {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let test_bed = TestBed::with_default_history().unwrap();
+
+    let output =
+        Command::new(exe).arg("--first-parent").current_dir(test_bed.path()).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains("--first-parent requires --from"));
+}
+
+#[test]
+fn test_cli_lint_range_json_output() {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+
+    let test_bed =
+        TestBed::with_history(&["chore: initial commit", "feat: test", "fea: test."]).unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[lint.header]\ntype-enum = [\"feat\"]",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(exe);
+    cmd.arg("--from").arg("HEAD~2").arg("--output-format").arg("json");
+    cmd.current_dir(test_bed.path());
+
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let sha = &test_bed.repo.get_log("HEAD~1", "HEAD").unwrap()[0].sha;
+    let document: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        document,
+        serde_json::json!([
+            {
+                "sha": sha,
+                "author": "Test User <test@example.com>",
+                "rule": "type-enum",
+                "group": "header",
+                "message": "Type is not allowed: fea",
+                "span": {
+                    "start": { "line": 1, "column": 1, "offset": 0 },
+                    "end": { "line": 1, "column": 4, "offset": 3 },
+                },
+                "fix_mode": "unfixable",
+                "severity": "error",
+                "fixed": false,
+            },
+            {
+                "sha": sha,
+                "author": "Test User <test@example.com>",
+                "rule": "description-full-stop",
+                "group": "header",
+                "message": "Header description is ended with a full stop",
+                "span": {
+                    "start": { "line": 1, "column": 6, "offset": 5 },
+                    "end": { "line": 1, "column": 11, "offset": 10 },
+                },
+                "fix_mode": "safe",
+                "severity": "error",
+                "fixed": false,
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_cli_lint_range_sarif_output() {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+
+    let test_bed =
+        TestBed::with_history(&["chore: initial commit", "feat: test", "fea: test"]).unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[lint.header]\ntype-enum = [\"feat\"]",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(exe);
+    cmd.arg("--from").arg("HEAD~2").arg("--output-format").arg("sarif");
+    cmd.current_dir(test_bed.path());
+
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let sha = &test_bed.repo.get_log("HEAD~1", "HEAD").unwrap()[0].sha;
+    let log: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "header.type-enum");
+    assert_eq!(
+        run["results"],
+        serde_json::json!([{
+            "ruleId": "header.type-enum",
+            "ruleIndex": 0,
+            "level": "error",
+            "message": { "text": "Type is not allowed: fea" },
+            "locations": [{
+                "logicalLocations": [{ "name": sha, "kind": "commit" }],
+            }],
+        }])
+    );
+}
+
+#[test]
+fn test_cli_lint_range_github_output() {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+
+    let test_bed =
+        TestBed::with_history(&["chore: initial commit", "feat: test", "fea: test"]).unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[lint.header]\ntype-enum = [\"feat\"]",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(exe);
+    cmd.arg("--from").arg("HEAD~2").arg("--output-format").arg("github");
+    cmd.current_dir(test_bed.path());
+
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let sha = &test_bed.repo.get_log("HEAD~1", "HEAD").unwrap()[0].sha;
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!("::error title=header.type-enum::Commit {sha}: Type is not allowed: fea\n")
+    );
+}
+
+#[test]
+fn test_cli_lint_range_junit_output() {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+
+    let test_bed = TestBed::with_history(&[
+        "chore: initial commit",
+        "Revert \"feat: test\"",
+        "feat: test",
+        "fea: test",
+    ])
+    .unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[lint.header]\ntype-enum = [\"feat\"]",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(exe);
+    cmd.arg("--from").arg("HEAD~3").arg("--output-format").arg("junit");
+    cmd.current_dir(test_bed.path());
+
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let log = test_bed.repo.get_log("HEAD~3", "HEAD").unwrap();
+    let output_text = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output_text.contains(r#"tests="3" failures="1" skipped="1""#));
+    assert!(output_text.contains(&format!(
+        r#"<testcase name="{}" classname="commitfmt">
+      <failure type="header.type-enum" message="Type is not allowed: fea">"#,
+        log[0].sha
+    )));
+    assert!(output_text
+        .contains(&format!(r#"<testcase name="{}" classname="commitfmt"/>"#, log[1].sha)));
+    assert!(output_text.contains(&format!(
+        r#"<testcase name="{}" classname="commitfmt">
+      <skipped message="Commit is ignored"/>"#,
+        log[2].sha
+    )));
+}
+
+#[test]
+fn test_baseline_suppresses_recorded_violations() {
+    let test_bed =
+        TestBed::with_history(&["chore: initial", "fea: old", "feat: ok", "fix: old."])
+            .unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[lint.header]\ntype-enum = [\"feat\", \"chore\"]\n",
+    )
+    .unwrap();
+
+    let mut commitfmt = Commitfmt::from_path(&test_bed.path()).unwrap();
+    assert_eq!(problems_count(&commitfmt.check_commit_range(("HEAD~3", "HEAD")).unwrap()), 3);
+
+    assert_eq!(commitfmt.write_baseline(("HEAD~3", "HEAD")).unwrap(), 3);
+    let commitfmt = Commitfmt::from_path(&test_bed.path()).unwrap();
+    assert_eq!(commitfmt.baseline().unwrap().len(), 3);
+    assert_eq!(problems_count(&commitfmt.check_commit_range(("HEAD~3", "HEAD")).unwrap()), 0);
+
+    test_bed.repo.commit("fea: new").unwrap();
+    let reports = commitfmt.check_commit_range(("HEAD~4", "HEAD")).unwrap();
+    assert_eq!(problems_count(&reports), 1);
+    assert_eq!(reports[0].diagnostics[0].rule_id(), "header.type-enum");
+}
+
+#[test]
+fn test_baseline_is_read_for_range_only() {
+    let test_bed = TestBed::with_history(&["chore: initial", "feat: ok"]).unwrap();
+    std::fs::write(test_bed.path().join(".commitfmt-baseline"), "4d71944 header.type-enum\n")
+        .unwrap();
+
+    let commitfmt = Commitfmt::from_path(&test_bed.path()).unwrap();
+    assert!(commitfmt.lint_commit_message("feat: message").is_ok());
+    assert!(matches!(
+        commitfmt.check_commit_range(("HEAD~1", "HEAD")),
+        Err(Error::InvalidBaseline(1))
+    ));
+}
+
+#[test]
+fn test_cli_baseline_write_and_prune() {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+    let test_bed = TestBed::with_history(&["chore: initial", "fea: old"]).unwrap();
+    std::fs::write(
+        test_bed.path().join(".commitfmt.toml"),
+        "[lint.header]\ntype-enum = [\"feat\", \"chore\"]\n",
+    )
+    .unwrap();
+
+    let output = Command::new(exe)
+        .args(["baseline", "write", "--from", "HEAD~1"])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout).unwrap().contains("Recorded 1 entry"));
+
+    let output = Command::new(exe)
+        .args(["--from", "HEAD~1"])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let amend = Command::new("git")
+        .args(["commit", "--amend", "--allow-empty", "--message", "fea: rewritten"])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(amend.status.success());
+
+    let output = Command::new(exe)
+        .args(["baseline", "prune"])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout).unwrap().contains("Removed 1 entry"));
+
+    let output = Command::new(exe)
+        .args(["--from", "HEAD~1"])
+        .current_dir(test_bed.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
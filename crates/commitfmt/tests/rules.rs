@@ -0,0 +1,33 @@
+use std::process::Command;
+
+#[test]
+fn test_rules() {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+
+    let output = Command::new(exe).arg("rules").output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    let description_full_stop =
+        stdout.lines().find(|line| line.starts_with("header.description-full-stop ")).unwrap();
+    let columns: Vec<&str> = description_full_stop.split_whitespace().collect();
+    assert_eq!(columns, ["header.description-full-stop", "safe", "on", "bool"]);
+
+    let type_enum = stdout.lines().find(|line| line.starts_with("header.type-enum ")).unwrap();
+    let columns: Vec<&str> = type_enum.split_whitespace().collect();
+    assert_eq!(columns, ["header.type-enum", "unfixable", "off", "array"]);
+}
+
+#[test]
+fn test_explain() {
+    let exe = env!("CARGO_BIN_EXE_commitfmt");
+
+    let output = Command::new(exe).args(["explain", "header.type-enum"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("# header.type-enum\n"));
+    assert!(stdout.contains("## What it does"));
+
+    let output = Command::new(exe).args(["explain", "header.unknown"]).output().unwrap();
+    assert!(!output.status.success());
+}